@@ -0,0 +1,229 @@
+use std::collections::HashMap;
+use std::fs;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use crate::cancel::Cancel;
+use crate::registry::{Day, Part, Year};
+
+const BASELINE_FILE: &str = "bench_baseline.txt";
+const DEFAULT_SAMPLES: usize = 20;
+
+// regressions need both a significant welch t statistic and a noticeable slowdown
+const T_CRITICAL: f64 = 3.0;
+const MIN_SLOWDOWN: f64 = 1.05;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct Stats {
+    mean: f64,
+    std_dev: f64,
+    samples: usize,
+}
+
+impl Stats {
+    fn from_samples(samples: &[f64]) -> Self {
+        let n = samples.len() as f64;
+        let mean = samples.iter().sum::<f64>() / n;
+        let variance = if samples.len() > 1 {
+            samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0)
+        } else {
+            0.0
+        };
+
+        Self {
+            mean,
+            std_dev: variance.sqrt(),
+            samples: samples.len(),
+        }
+    }
+
+    fn welch_t(&self, baseline: &Stats) -> f64 {
+        let se = (self.std_dev.powi(2) / self.samples as f64
+            + baseline.std_dev.powi(2) / baseline.samples as f64)
+            .sqrt();
+        let diff = self.mean - baseline.mean;
+
+        if se == 0.0 {
+            if diff > 0.0 { f64::INFINITY } else { 0.0 }
+        } else {
+            diff / se
+        }
+    }
+
+    fn is_regression(&self, baseline: &Stats) -> bool {
+        self.welch_t(baseline) > T_CRITICAL && self.mean > baseline.mean * MIN_SLOWDOWN
+    }
+}
+
+type Key = (u16, String, usize);
+
+fn parse_baseline(contents: &str) -> HashMap<Key, Stats> {
+    contents
+        .lines()
+        .filter_map(|line| {
+            let mut iter = line.split_ascii_whitespace();
+            let year = iter.next()?.parse().ok()?;
+            let day = iter.next()?.to_string();
+            let part = iter.next()?.parse().ok()?;
+            let mean = iter.next()?.parse().ok()?;
+            let std_dev = iter.next()?.parse().ok()?;
+            let samples = iter.next()?.parse().ok()?;
+            Some((
+                (year, day, part),
+                Stats {
+                    mean,
+                    std_dev,
+                    samples,
+                },
+            ))
+        })
+        .collect()
+}
+
+fn format_baseline(baseline: &HashMap<Key, Stats>) -> String {
+    let mut keys = baseline.keys().collect::<Vec<&Key>>();
+    keys.sort();
+
+    keys.into_iter()
+        .map(|key| {
+            let stats = baseline[key];
+            format!(
+                "{} {} {} {} {} {}\n",
+                key.0, key.1, key.2, stats.mean, stats.std_dev, stats.samples
+            )
+        })
+        .collect()
+}
+
+fn measure(part: Part, input: &[u8], samples: usize) -> Stats {
+    black_box(part(black_box(input), &Cancel::default()).unwrap());
+
+    let timings = (0..samples)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(part(black_box(input), &Cancel::default()).unwrap());
+            start.elapsed().as_nanos() as f64
+        })
+        .collect::<Vec<f64>>();
+
+    Stats::from_samples(&timings)
+}
+
+fn format_nanos(nanos: f64) -> String {
+    format!("{:?}", Duration::from_nanos(nanos as u64))
+}
+
+pub fn main(year: &Year, mut args: impl Iterator<Item = String>) {
+    let mut save = false;
+    let mut baseline_file = BASELINE_FILE.to_string();
+    let mut samples = DEFAULT_SAMPLES;
+    let mut days: Vec<&Day> = Vec::new();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--save" => save = true,
+            "--baseline" => baseline_file = args.next().expect("missing value for --baseline"),
+            "--samples" => {
+                samples = args
+                    .next()
+                    .and_then(|s| s.parse().ok())
+                    .filter(|&n| n > 0)
+                    .expect("invalid value for --samples")
+            }
+            day => days.push(year.expect_day(day)),
+        }
+    }
+
+    if days.is_empty() {
+        days = year.days.iter().collect();
+    }
+
+    let mut baseline = fs::read_to_string(&baseline_file)
+        .map(|contents| parse_baseline(&contents))
+        .unwrap_or_default();
+    let mut regressions = 0;
+
+    for day in days {
+        let input = day.input.trim_ascii_end();
+        for (ix, &part) in day.parts.iter().enumerate() {
+            let key = (year.year, day.name.to_string(), ix + 1);
+            let stats = measure(part, input, samples);
+
+            print!(
+                "{} day {} part {}: {} ± {}",
+                key.0,
+                key.1,
+                key.2,
+                format_nanos(stats.mean),
+                format_nanos(stats.std_dev)
+            );
+
+            if let Some(previous) = baseline.get(&key) {
+                let change = (stats.mean / previous.mean - 1.0) * 100.0;
+                print!(
+                    " (baseline {}, {:+.1}%)",
+                    format_nanos(previous.mean),
+                    change
+                );
+                if stats.is_regression(previous) {
+                    regressions += 1;
+                    print!(" REGRESSION");
+                }
+            }
+            println!();
+
+            if save {
+                baseline.insert(key, stats);
+            }
+        }
+    }
+
+    if save {
+        fs::write(&baseline_file, format_baseline(&baseline)).unwrap();
+    }
+
+    if regressions > 0 {
+        eprintln!("{} regression(s) against {}", regressions, baseline_file);
+        std::process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::from_samples(&[2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]);
+        assert_eq!(stats.mean, 5.0);
+        assert_eq!(stats.samples, 8);
+        assert!((stats.std_dev - (32.0f64 / 7.0).sqrt()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_regression() {
+        let baseline = Stats::from_samples(&[100.0, 101.0, 99.0, 100.0, 100.0]);
+        let noisy = Stats::from_samples(&[90.0, 130.0, 95.0, 110.0, 100.0]);
+        let slower = Stats::from_samples(&[120.0, 121.0, 119.0, 120.0, 120.0]);
+        let faster = Stats::from_samples(&[80.0, 81.0, 79.0, 80.0, 80.0]);
+
+        assert!(!noisy.is_regression(&baseline));
+        assert!(slower.is_regression(&baseline));
+        assert!(!faster.is_regression(&baseline));
+    }
+
+    #[test]
+    fn test_baseline_roundtrip() {
+        let mut baseline = HashMap::new();
+        baseline.insert(
+            (2024, "06".to_string(), 2),
+            Stats::from_samples(&[1000.0, 1100.0, 1050.0]),
+        );
+        baseline.insert(
+            (2024, "22".to_string(), 1),
+            Stats::from_samples(&[50.5, 49.5]),
+        );
+
+        assert_eq!(parse_baseline(&format_baseline(&baseline)), baseline);
+    }
+}
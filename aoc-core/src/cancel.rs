@@ -0,0 +1,97 @@
+use std::fmt;
+use std::panic;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Default)]
+pub struct Cancel(Arc<AtomicBool>);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cancelled;
+
+impl Cancel {
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    pub fn check(&self) -> Result<(), Cancelled> {
+        if self.is_cancelled() {
+            Err(Cancelled)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl fmt::Display for Cancelled {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "cancelled")
+    }
+}
+
+pub fn with_timeout<T, F>(timeout: Option<Duration>, f: F) -> Result<T, Cancelled>
+where
+    T: Send + 'static,
+    F: FnOnce(&Cancel) -> Result<T, Cancelled> + Send + 'static,
+{
+    let cancel = Cancel::default();
+
+    let Some(timeout) = timeout else {
+        return f(&cancel);
+    };
+
+    let (tx, rx) = mpsc::channel();
+    let token = cancel.clone();
+    let handle = thread::spawn(move || {
+        let _ = tx.send(f(&token));
+    });
+
+    match rx.recv_timeout(timeout) {
+        Ok(result) => result,
+        Err(RecvTimeoutError::Timeout) => {
+            cancel.cancel();
+            Err(Cancelled)
+        }
+        Err(RecvTimeoutError::Disconnected) => match handle.join() {
+            Err(payload) => panic::resume_unwind(payload),
+            Ok(()) => unreachable!(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_with_timeout() {
+        assert_eq!(with_timeout(None, |_| Ok(42)), Ok(42));
+        assert_eq!(
+            with_timeout(Some(Duration::from_secs(10)), |_| Ok(42)),
+            Ok(42)
+        );
+
+        let result: Result<(), Cancelled> =
+            with_timeout(Some(Duration::from_millis(10)), |cancel| {
+                loop {
+                    cancel.check()?;
+                    thread::sleep(Duration::from_millis(1));
+                }
+            });
+        assert_eq!(result, Err(Cancelled));
+    }
+
+    #[test]
+    #[should_panic(expected = "boom")]
+    fn test_with_timeout_panic() {
+        let _: Result<(), Cancelled> =
+            with_timeout(Some(Duration::from_secs(10)), |_| panic!("boom"));
+    }
+}
@@ -0,0 +1,151 @@
+use std::ops::{Index, IndexMut};
+
+use crate::parse::ParseError;
+
+// row major, positions are (row, col)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T = u8> {
+    pub rows: usize,
+    pub cols: usize,
+    pub cells: Vec<T>,
+}
+
+const ORTHOGONAL: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+impl Grid<u8> {
+    // one line per row, every row as long as the first
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::parse(bytes, Ok)
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn parse(
+        bytes: &[u8],
+        mut cell: impl FnMut(u8) -> Result<T, ParseError>,
+    ) -> Result<Self, ParseError> {
+        let cols = bytes.iter().take_while(|&&b| b != b'\n').count();
+        if cols == 0 {
+            return Err(ParseError::Empty);
+        }
+
+        let mut rows = 0;
+        let mut cells = Vec::with_capacity(bytes.len());
+        for line in bytes.split(|&b| b == b'\n') {
+            if line.len() != cols {
+                return Err(ParseError::RaggedRow(rows));
+            }
+            for &b in line {
+                cells.push(cell(b)?);
+            }
+            rows += 1;
+        }
+
+        Ok(Self { rows, cols, cells })
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            rows: self.rows,
+            cols: self.cols,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    pub fn index_of(&self, (row, col): (usize, usize)) -> usize {
+        row * self.cols + col
+    }
+
+    pub fn coords(&self, index: usize) -> (usize, usize) {
+        (index / self.cols, index % self.cols)
+    }
+
+    pub fn contains(&self, (row, col): (isize, isize)) -> bool {
+        0 <= row && row < self.rows as isize && 0 <= col && col < self.cols as isize
+    }
+
+    pub fn get(&self, pos: (isize, isize)) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.0 as usize * self.cols + pos.1 as usize])
+    }
+
+    pub fn position(&self, f: impl Fn(&T) -> bool) -> Option<(usize, usize)> {
+        self.cells.iter().position(f).map(|ix| self.coords(ix))
+    }
+
+    // the cells of the outermost rows and columns
+    pub fn border(&self) -> impl Iterator<Item = &T> + '_ {
+        self.cells.iter().enumerate().filter_map(move |(ix, cell)| {
+            let (row, col) = self.coords(ix);
+            (row == 0 || col == 0 || row == self.rows - 1 || col == self.cols - 1).then_some(cell)
+        })
+    }
+
+    // up, right, down, left, skipping the ones off the grid
+    pub fn neighbours(
+        &self,
+        (row, col): (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        ORTHOGONAL.iter().filter_map(move |&(dr, dc)| {
+            let pos = (row as isize + dr, col as isize + dc);
+            self.contains(pos)
+                .then_some((pos.0 as usize, pos.1 as usize))
+        })
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: (usize, usize)) -> &T {
+        &self.cells[self.index_of(pos)]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, pos: (usize, usize)) -> &mut T {
+        let ix = self.index_of(pos);
+        &mut self.cells[ix]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_bytes() {
+        let grid = Grid::from_bytes(b"ab\ncd\nef").unwrap();
+        assert_eq!((grid.rows, grid.cols), (3, 2));
+        assert_eq!(grid[(2, 1)], b'f');
+        assert_eq!(grid.get((1, 0)), Some(&b'c'));
+        assert_eq!(grid.get((1, 2)), None);
+        assert_eq!(grid.get((-1, 0)), None);
+        assert_eq!(grid.position(|&b| b == b'd'), Some((1, 1)));
+        assert_eq!(grid.coords(grid.index_of((2, 0))), (2, 0));
+        assert_eq!(grid.border().count(), 6);
+
+        let grid = Grid::from_bytes(b"abc\ndef\nghi").unwrap();
+        assert_eq!(grid.border().copied().collect::<Vec<u8>>(), b"abcdfghi");
+
+        assert_eq!(Grid::from_bytes(b""), Err(ParseError::Empty));
+        assert_eq!(Grid::from_bytes(b"ab\nc"), Err(ParseError::RaggedRow(1)));
+        assert_eq!(Grid::from_bytes(b"ab\n"), Err(ParseError::RaggedRow(1)));
+    }
+
+    #[test]
+    fn test_parse() {
+        let digits = Grid::parse(b"12\n34", |b| match b {
+            b'0'..=b'9' => Ok(b - b'0'),
+            _ => Err(ParseError::InvalidByte(b)),
+        });
+        assert_eq!(digits.unwrap().cells, [1, 2, 3, 4]);
+
+        let walls = Grid::from_bytes(b"#.\n.#").unwrap().map(|&b| b == b'#');
+        assert_eq!(walls.cells, [true, false, false, true]);
+        assert_eq!(
+            walls.neighbours((0, 0)).collect::<Vec<_>>(),
+            [(0, 1), (1, 0)]
+        );
+    }
+}
@@ -0,0 +1,189 @@
+use std::any::Any;
+use std::env;
+use std::io::{self, Write};
+use std::panic;
+use std::process;
+use std::slice;
+use std::time::{Duration, Instant};
+
+use output::{Answer, Format, Report};
+use registry::{Day, Year};
+
+pub mod bench;
+pub mod cancel;
+pub mod grid;
+pub mod output;
+pub mod parse;
+pub mod registry;
+pub mod repl;
+pub mod scaffold;
+pub mod search;
+pub mod server;
+pub mod watch;
+
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    match payload.downcast_ref::<&str>() {
+        Some(message) => message,
+        None => payload
+            .downcast_ref::<String>()
+            .map_or("unknown", |message| message.as_str()),
+    }
+}
+
+// failed parts are reported as error rows so the output stays well formed, returns false if any
+// part failed; after a timeout the remaining parts of the day are skipped, and as solvers only
+// stop at their next cancel check the timed out one may keep a core busy in the background,
+// which slows down the days that run after it and skews their timings
+fn run_day<W: Write>(
+    year: &Year,
+    day: &Day,
+    timeout: Option<Duration>,
+    report: &mut Report<W>,
+) -> bool {
+    let input = day.input.trim_ascii_end();
+    let mut ok = true;
+
+    for (ix, &part) in day.parts.iter().enumerate() {
+        let start = Instant::now();
+        let result = panic::catch_unwind(|| {
+            cancel::with_timeout(timeout, move |cancel| part(input, cancel))
+        });
+        let row = |answer| Answer {
+            year: year.year,
+            day: day.name,
+            part: ix + 1,
+            answer,
+            elapsed: start.elapsed(),
+        };
+
+        match result {
+            Ok(Ok(answer)) => report.answer(&row(&answer)),
+            Ok(Err(_)) => {
+                let error = match timeout {
+                    Some(timeout) => format!("timed out after {:?}", timeout),
+                    None => "cancelled".to_string(),
+                };
+                report.error(&row(&error));
+                return false;
+            }
+            Err(payload) => {
+                let error = format!("panicked: {}", panic_message(payload.as_ref()));
+                report.error(&row(&error));
+                ok = false;
+            }
+        }
+    }
+
+    ok
+}
+
+pub fn main(years: &[&'static Year]) {
+    let mut year = *years.last().expect("no years registered");
+    let mut timeout = None;
+    let mut format = Format::default();
+    let mut args = vec![];
+
+    let mut iter = env::args().skip(1);
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--year" => {
+                let value = iter.next().expect("missing value for --year");
+                year = years
+                    .iter()
+                    .find(|y| y.year.to_string() == value)
+                    .unwrap_or_else(|| panic!("invalid argument for \"year\": {}", value));
+            }
+            "--timeout" => {
+                let value = iter.next().expect("missing value for --timeout");
+                let secs = value
+                    .parse()
+                    .unwrap_or_else(|_| panic!("invalid argument for \"timeout\": {}", value));
+                timeout = Some(Duration::from_secs_f64(secs));
+            }
+            "--format" => {
+                let value = iter.next().expect("missing value for --format");
+                format = Format::parse(&value)
+                    .unwrap_or_else(|| panic!("invalid argument for \"format\": {}", value));
+            }
+            "--day" => args.push(iter.next().expect("missing value for --day")),
+            _ => args.push(arg),
+        }
+    }
+
+    let mut args = args.into_iter();
+
+    match args.next().as_deref() {
+        Some("bench") => bench::main(year, args),
+        Some("new") => scaffold::main(year, args),
+        Some("repl") => repl::main(year, args),
+        Some("serve") => server::main(years, timeout, args),
+        Some("watch") => watch::main(year, args),
+        day => {
+            let days = match day {
+                Some(day) => slice::from_ref(year.expect_day(day)),
+                None => year.days,
+            };
+
+            let mut report = Report::new(io::stdout(), format);
+            let mut ok = true;
+            for day in days {
+                ok &= run_day(year, day, timeout, &mut report);
+            }
+            report.finish();
+
+            if !ok {
+                process::exit(1);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use output::Format;
+
+    static DAY: Day = Day {
+        name: "01",
+        input: b"abc",
+        parse: |_| Ok(()),
+        parts: &[
+            |_, _| panic!("boom"),
+            |input, _| Ok(input.len().to_string()),
+            |_, cancel| loop {
+                cancel.check()?;
+            },
+            |_, _| Ok("skipped".to_string()),
+        ],
+    };
+
+    static YEAR: Year = Year {
+        year: 2024,
+        package: "",
+        root: "",
+        days: slice::from_ref(&DAY),
+        commands: |_| &[],
+    };
+
+    #[test]
+    fn test_run_day() {
+        let mut report = Report::new(vec![], Format::Csv);
+        let timeout = Some(Duration::from_millis(10));
+        assert!(!run_day(&YEAR, &DAY, timeout, &mut report));
+
+        let output = String::from_utf8(report.finish()).unwrap();
+        let rows = output
+            .lines()
+            .map(|line| line.rsplit_once(',').unwrap().0)
+            .collect::<Vec<&str>>();
+        assert_eq!(
+            rows,
+            [
+                "year,day,part,answer,type",
+                "2024,01,1,panicked: boom,error",
+                "2024,01,2,3,integer",
+                "2024,01,3,timed out after 10ms,error",
+            ]
+        );
+    }
+}
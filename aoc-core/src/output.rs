@@ -0,0 +1,209 @@
+use std::io::Write;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+pub struct Answer<'a> {
+    pub year: u16,
+    pub day: &'a str,
+    pub part: usize,
+    pub answer: &'a str,
+    pub elapsed: Duration,
+}
+
+// answers are strings by the time they leave a solver, so the type is recovered from the text
+pub fn answer_type(answer: &str) -> &'static str {
+    if answer.parse::<i128>().is_ok() {
+        "integer"
+    } else {
+        "string"
+    }
+}
+
+pub fn json_string(s: &str) -> String {
+    let mut result = String::with_capacity(s.len() + 2);
+    result.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            c if (c as u32) < 0x20 => result.push_str(&format!("\\u{:04x}", c as u32)),
+            c => result.push(c),
+        }
+    }
+    result.push('"');
+    result
+}
+
+// one answer as a json object, shared by the json report and the server
+pub fn json_answer(answer: &Answer) -> String {
+    json_row(answer, answer_type(answer.answer))
+}
+
+fn json_row(answer: &Answer, kind: &str) -> String {
+    format!(
+        "{{\"year\":{},\"day\":{},\"part\":{},\"answer\":{},\"type\":{},\"time_ns\":{}}}",
+        answer.year,
+        json_string(answer.day),
+        answer.part,
+        json_string(answer.answer),
+        json_string(kind),
+        answer.elapsed.as_nanos()
+    )
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+impl Format {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "text" => Some(Self::Text),
+            "json" => Some(Self::Json),
+            "csv" => Some(Self::Csv),
+            _ => None,
+        }
+    }
+}
+
+pub struct Report<W: Write> {
+    out: W,
+    format: Format,
+    count: usize,
+}
+
+impl<W: Write> Report<W> {
+    pub fn new(out: W, format: Format) -> Self {
+        let mut report = Self {
+            out,
+            format,
+            count: 0,
+        };
+        match format {
+            Format::Text => {}
+            Format::Json => write!(report.out, "[").unwrap(),
+            Format::Csv => writeln!(report.out, "year,day,part,answer,type,time_ns").unwrap(),
+        }
+        report
+    }
+
+    pub fn answer(&mut self, answer: &Answer) {
+        self.row(answer, answer_type(answer.answer));
+    }
+
+    // a part that failed, with what went wrong in place of the answer
+    pub fn error(&mut self, answer: &Answer) {
+        self.row(answer, "error");
+    }
+
+    fn row(&mut self, answer: &Answer, kind: &str) {
+        self.count += 1;
+
+        match self.format {
+            Format::Text => writeln!(
+                self.out,
+                "{} day {} part {}: {} ({}, {:?})",
+                answer.year, answer.day, answer.part, answer.answer, kind, answer.elapsed
+            ),
+            Format::Json => write!(
+                self.out,
+                "{}\n  {}",
+                if self.count > 1 { "," } else { "" },
+                json_row(answer, kind)
+            ),
+            Format::Csv => writeln!(
+                self.out,
+                "{},{},{},{},{},{}",
+                answer.year,
+                csv_field(answer.day),
+                answer.part,
+                csv_field(answer.answer),
+                kind,
+                answer.elapsed.as_nanos()
+            ),
+        }
+        .unwrap();
+        self.out.flush().unwrap();
+    }
+
+    pub fn finish(mut self) -> W {
+        if self.format == Format::Json {
+            writeln!(self.out, "\n]").unwrap();
+        }
+        self.out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn answer(answer: &str) -> Answer<'_> {
+        Answer {
+            year: 2024,
+            day: "01",
+            part: 2,
+            answer,
+            elapsed: Duration::from_nanos(1500),
+        }
+    }
+
+    fn render(format: Format, answers: &[&str]) -> String {
+        let mut report = Report::new(vec![], format);
+        for a in answers {
+            report.answer(&answer(a));
+        }
+        String::from_utf8(report.finish()).unwrap()
+    }
+
+    #[test]
+    fn test_answer_type() {
+        assert_eq!(answer_type("42"), "integer");
+        assert_eq!(answer_type("-7"), "integer");
+        assert_eq!(answer_type("4,6,3"), "string");
+        assert_eq!(answer_type("co,de,ka,ta"), "string");
+    }
+
+    #[test]
+    fn test_json_string() {
+        assert_eq!(json_string("a\"b\\c\nd"), "\"a\\\"b\\\\c\\nd\"");
+    }
+
+    #[test]
+    fn test_report() {
+        assert_eq!(
+            render(Format::Text, &["42"]),
+            "2024 day 01 part 2: 42 (integer, 1.5µs)\n"
+        );
+        assert_eq!(
+            render(Format::Json, &["42", "4,6,3"]),
+            "[\n  {\"year\":2024,\"day\":\"01\",\"part\":2,\"answer\":\"42\",\"type\":\"integer\",\"time_ns\":1500},\n  {\"year\":2024,\"day\":\"01\",\"part\":2,\"answer\":\"4,6,3\",\"type\":\"string\",\"time_ns\":1500}\n]\n"
+        );
+        assert_eq!(render(Format::Json, &[]), "[\n]\n");
+
+        let mut report = Report::new(vec![], Format::Csv);
+        report.error(&answer("timed out after 1s"));
+        assert_eq!(
+            String::from_utf8(report.finish()).unwrap(),
+            "year,day,part,answer,type,time_ns\n2024,01,2,timed out after 1s,error,1500\n"
+        );
+        assert_eq!(
+            render(Format::Csv, &["4,6,3"]),
+            "year,day,part,answer,type,time_ns\n2024,01,2,\"4,6,3\",string,1500\n"
+        );
+    }
+}
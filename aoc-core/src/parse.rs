@@ -0,0 +1,100 @@
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseError {
+    Empty,
+    InvalidByte(u8),
+    Overflow,
+    // rows of a grid are numbered from 0
+    RaggedRow(usize),
+    Missing(&'static str),
+    // well formed but not something the puzzle can be solved for
+    Invalid(&'static str),
+    Utf8,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseError::Empty => write!(f, "empty input"),
+            ParseError::InvalidByte(b) => write!(f, "invalid byte: {:?}", *b as char),
+            ParseError::Overflow => write!(f, "number out of range"),
+            ParseError::RaggedRow(row) => write!(f, "row {} has a different length", row),
+            ParseError::Missing(what) => write!(f, "missing {}", what),
+            ParseError::Invalid(why) => write!(f, "invalid input: {}", why),
+            ParseError::Utf8 => write!(f, "invalid utf-8"),
+        }
+    }
+}
+
+pub trait Integer: Copy + Default {
+    const SIGNED: bool;
+
+    // self * 10 + digit, or - digit while reading a negative number
+    fn push_digit(self, digit: u8, negative: bool) -> Option<Self>;
+}
+
+macro_rules! integer {
+    ($($t:ty: $signed:expr),+) => {
+        $(impl Integer for $t {
+            const SIGNED: bool = $signed;
+
+            fn push_digit(self, digit: u8, negative: bool) -> Option<Self> {
+                let shifted = self.checked_mul(10)?;
+                if negative {
+                    shifted.checked_sub(digit as Self)
+                } else {
+                    shifted.checked_add(digit as Self)
+                }
+            }
+        })+
+    };
+}
+
+integer!(
+    u8: false, u16: false, u32: false, u64: false, u128: false, usize: false,
+    i8: true, i16: true, i32: true, i64: true, i128: true, isize: true
+);
+
+// decimal digits with an optional leading '-' for signed types, nothing else
+pub fn integer<T: Integer>(bytes: &[u8]) -> Result<T, ParseError> {
+    let (negative, digits) = match bytes.split_first() {
+        Some((b'-', digits)) if T::SIGNED => (true, digits),
+        _ => (false, bytes),
+    };
+    if digits.is_empty() {
+        return Err(ParseError::Empty);
+    }
+
+    digits.iter().try_fold(T::default(), |acc, &b| {
+        if !b.is_ascii_digit() {
+            return Err(ParseError::InvalidByte(b));
+        }
+        acc.push_digit(b - b'0', negative)
+            .ok_or(ParseError::Overflow)
+    })
+}
+
+pub fn utf8(bytes: &[u8]) -> Result<&str, ParseError> {
+    std::str::from_utf8(bytes).map_err(|_| ParseError::Utf8)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_integer() {
+        assert_eq!(integer::<u32>(b"12345"), Ok(12345));
+        assert_eq!(integer::<i64>(b"-42"), Ok(-42));
+        assert_eq!(integer::<i8>(b"-128"), Ok(-128));
+        assert_eq!(integer::<usize>(b"007"), Ok(7));
+
+        assert_eq!(integer::<u32>(b""), Err(ParseError::Empty));
+        assert_eq!(integer::<i32>(b"-"), Err(ParseError::Empty));
+        assert_eq!(integer::<u32>(b"-1"), Err(ParseError::InvalidByte(b'-')));
+        assert_eq!(integer::<u32>(b"1 2"), Err(ParseError::InvalidByte(b' ')));
+        assert_eq!(integer::<u8>(b"256"), Err(ParseError::Overflow));
+        assert_eq!(integer::<i8>(b"128"), Err(ParseError::Overflow));
+    }
+}
@@ -0,0 +1,60 @@
+use crate::cancel::{Cancel, Cancelled};
+use crate::parse::ParseError;
+
+// checks the input the parts expect without solving anything
+pub type Parse = fn(&[u8]) -> Result<(), ParseError>;
+
+pub type Part = fn(&[u8], &Cancel) -> Result<String, Cancelled>;
+
+pub type Command = (&'static str, &'static str, fn(&[u8], &[&str]) -> String);
+
+pub struct Day {
+    pub name: &'static str,
+    pub input: &'static [u8],
+    pub parse: Parse,
+    pub parts: &'static [Part],
+}
+
+pub struct Year {
+    pub year: u16,
+    pub package: &'static str,
+    pub root: &'static str,
+    pub days: &'static [Day],
+    pub commands: fn(&str) -> &'static [Command],
+}
+
+impl Year {
+    pub fn day(&self, name: &str) -> Option<&'static Day> {
+        let name = format!("{:02}", name.parse::<u8>().ok()?);
+        self.days.iter().find(|day| day.name == name)
+    }
+
+    pub fn expect_day(&self, name: &str) -> &'static Day {
+        self.day(name)
+            .unwrap_or_else(|| panic!("invalid argument for \"day\": {}", name))
+    }
+}
+
+pub fn str_from_bytes(bytes: &[u8]) -> &str {
+    std::str::from_utf8(bytes).unwrap()
+}
+
+#[macro_export]
+macro_rules! parse {
+    (bytes: $parse:path) => {
+        |input| $parse(input).map(drop)
+    };
+    (str: $parse:path) => {
+        |input| $parse($crate::parse::utf8(input)?).map(drop)
+    };
+}
+
+#[macro_export]
+macro_rules! parts {
+    (bytes: $($part:path),+) => {
+        &[$(|input, _| Ok($part(input).to_string())),+]
+    };
+    (str: $($part:path),+) => {
+        &[$(|input, _| Ok($part($crate::registry::str_from_bytes(input)).to_string())),+]
+    };
+}
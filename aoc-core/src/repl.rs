@@ -0,0 +1,96 @@
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::panic::{self, AssertUnwindSafe};
+use std::time::Instant;
+
+use crate::cancel::Cancel;
+use crate::registry::{Command, Day, Year};
+
+fn help(day: &Day, commands: &[Command]) -> String {
+    let mut lines = (1..=day.parts.len())
+        .map(|part| format!("  {:<24} run part {}", part, part))
+        .collect::<Vec<String>>();
+
+    for (name, usage, _) in commands {
+        lines.push(format!("  {} {}", name, usage).trim_end().to_string());
+    }
+
+    lines.push(format!(
+        "  {:<24} reload input from file or built-in input",
+        "load [path]"
+    ));
+    lines.push(format!("  {:<24} leave", "quit"));
+
+    lines.join("\n")
+}
+
+fn timed(f: impl FnOnce() -> String) {
+    let start = Instant::now();
+    let output = panic::catch_unwind(AssertUnwindSafe(f));
+    let elapsed = start.elapsed();
+
+    if let Ok(output) = output {
+        println!("{}", output);
+    }
+    println!("({:?})", elapsed);
+}
+
+pub fn main(year: &Year, mut args: impl Iterator<Item = String>) {
+    let name = args.next().expect("usage: repl <day> [input file]");
+    let day = year.expect_day(&name);
+    let mut input = match args.next() {
+        Some(path) => fs::read(path).unwrap(),
+        None => day.input.to_vec(),
+    };
+    let commands = (year.commands)(day.name);
+
+    let mut lines = io::stdin().lock().lines();
+
+    loop {
+        print!("day {}> ", day.name);
+        io::stdout().flush().unwrap();
+
+        let Some(Ok(line)) = lines.next() else {
+            break;
+        };
+        let words = line.split_ascii_whitespace().collect::<Vec<&str>>();
+        let Some((&command, args)) = words.split_first() else {
+            continue;
+        };
+
+        match command {
+            "quit" | "exit" => break,
+            "help" => println!("{}", help(day, commands)),
+            "load" => {
+                match args.first() {
+                    Some(path) => match fs::read(path) {
+                        Ok(bytes) => input = bytes,
+                        Err(err) => {
+                            println!("{}: {}", path, err);
+                            continue;
+                        }
+                    },
+                    None => input = day.input.to_vec(),
+                }
+                println!("loaded {} bytes", input.len());
+            }
+            _ => {
+                let input = input.trim_ascii_end();
+                if let Some(&part) = command
+                    .parse::<usize>()
+                    .ok()
+                    .and_then(|n| day.parts.get(n.checked_sub(1)?))
+                {
+                    timed(|| match part(input, &Cancel::default()) {
+                        Ok(answer) => answer,
+                        Err(err) => err.to_string(),
+                    });
+                } else if let Some((_, _, run)) = commands.iter().find(|(n, _, _)| *n == command) {
+                    timed(|| run(input, args));
+                } else {
+                    println!("unknown command: {} (try \"help\")", command);
+                }
+            }
+        }
+    }
+}
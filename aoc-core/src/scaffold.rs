@@ -0,0 +1,387 @@
+use std::fs;
+use std::path::Path;
+
+use crate::registry::Year;
+
+const TEMPLATE: &str = r#"extern crate test;
+
+use aoc_core::parse::ParseError;
+
+pub const INPUT: &[u8] = include_bytes!("../inputs/dayNN.txt");
+
+pub fn parse(input: &[u8]) -> Result<Vec<&[u8]>, ParseError> {
+    Ok(input.split(|&b| b == b'\n').collect())
+}
+
+pub fn part1(input: &[u8]) -> usize {
+    let _lines = parse(input).unwrap();
+    0
+}
+
+pub fn part2(input: &[u8]) -> usize {
+    let _lines = parse(input).unwrap();
+    0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test::Bencher;
+
+    const TEST_INPUT: &[u8] = include_bytes!("../test_inputs/dayNN.txt");
+
+    #[test]
+    fn test_part1() {
+        let input = TEST_INPUT.trim_ascii_end();
+        assert_eq!(part1(input), 0);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = TEST_INPUT.trim_ascii_end();
+        assert_eq!(part2(input), 0);
+    }
+
+    #[bench]
+    fn bench_part1(b: &mut Bencher) {
+        let input = INPUT.trim_ascii_end();
+        b.iter(|| part1(input))
+    }
+
+    #[bench]
+    fn bench_part2(b: &mut Bencher) {
+        let input = INPUT.trim_ascii_end();
+        b.iter(|| part2(input))
+    }
+}
+"#;
+
+const FUZZ_TARGET: &str = r#"#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &[u8]| aoc_fuzz::solve(&CRATE::YEAR, "NN", input));
+"#;
+
+const YEAR_MANIFEST: &str = r#"[package]
+name = "aoc-yYYYY"
+version = "0.1.0"
+edition = "2024"
+
+[dependencies]
+aoc-core = { path = "../aoc-core" }
+"#;
+
+const YEAR_LIB: &str = r#"#![feature(test)]
+
+use aoc_core::registry::Year;
+
+mod registry;
+
+pub static YEAR: Year = Year {
+    year: YYYY,
+    package: env!("CARGO_PKG_NAME"),
+    root: env!("CARGO_MANIFEST_DIR"),
+    days: registry::DAYS,
+    commands: registry::commands,
+};
+"#;
+
+const YEAR_REGISTRY: &str = r#"use aoc_core::registry::{Command, Day};
+use aoc_core::{parse, parts};
+
+use crate::*;
+
+pub static DAYS: &[Day] = &[
+];
+
+pub fn commands(_day: &str) -> &'static [Command] {
+    &[]
+}
+"#;
+
+fn render_module(name: &str) -> String {
+    TEMPLATE.replace("NN", name)
+}
+
+fn render_fuzz_target(package: &str, name: &str) -> String {
+    FUZZ_TARGET
+        .replace("CRATE", &package.replace('-', "_"))
+        .replace("NN", name)
+}
+
+fn register_module(lib_rs: &str, name: &str) -> String {
+    let module = format!("pub mod day{};", name);
+    let mut lines = lib_rs.lines().collect::<Vec<&str>>();
+    let ix = lines
+        .iter()
+        .position(|line| line.starts_with("pub mod day") && *line > module.as_str())
+        .or_else(|| {
+            lines
+                .iter()
+                .rposition(|line| line.starts_with("pub mod day"))
+                .map(|ix| ix + 1)
+        })
+        // a fresh year has no days yet, they go in front of the registry
+        .or_else(|| lines.iter().position(|line| *line == "mod registry;"))
+        .expect("no day modules in lib.rs");
+
+    lines.insert(ix, &module);
+    lines.join("\n") + "\n"
+}
+
+fn register_day(registry_rs: &str, name: &str) -> String {
+    let entry = format!(
+        "    Day {{\n        name: \"{name}\",\n        input: day{name}::INPUT,\n        parse: parse!(bytes: day{name}::parse),\n        parts: parts!(bytes: day{name}::part1, day{name}::part2),\n    }},\n",
+    );
+    let ix = registry_rs
+        .find("\n];")
+        .expect("no day list in registry.rs")
+        + 1;
+
+    format!("{}{}{}", &registry_rs[..ix], entry, &registry_rs[ix..])
+}
+
+fn register_fuzz_target(fuzz_toml: &str, target: &str) -> String {
+    format!(
+        "{}\n\n[[bin]]\nname = \"{target}\"\npath = \"fuzz_targets/{target}.rs\"\ntest = false\ndoc = false\nbench = false\n",
+        fuzz_toml.trim_end(),
+    )
+}
+
+fn register_member(workspace_toml: &str, member: &str) -> String {
+    let start = workspace_toml
+        .find("members = [")
+        .expect("no members in Cargo.toml");
+    let ix = start + workspace_toml[start..].find(']').unwrap();
+
+    format!(
+        "{}, \"{}\"{}",
+        &workspace_toml[..ix],
+        member,
+        &workspace_toml[ix..]
+    )
+}
+
+fn register_dependency(cargo_toml: &str, package: &str, dir: &str) -> String {
+    let dependency = format!("{package} = {{ path = \"../{dir}\" }}");
+    let mut lines = cargo_toml.lines().collect::<Vec<&str>>();
+    let ix = lines
+        .iter()
+        .rposition(|line| line.starts_with("aoc-y"))
+        .expect("no year dependencies in Cargo.toml")
+        + 1;
+
+    lines.insert(ix, &dependency);
+    lines.join("\n") + "\n"
+}
+
+fn register_year(main_rs: &str, package: &str) -> String {
+    let ix = main_rs.rfind("::YEAR]").expect("no years in main.rs") + "::YEAR".len();
+
+    format!(
+        "{}, &{}::YEAR{}",
+        &main_rs[..ix],
+        package.replace('-', "_"),
+        &main_rs[ix..]
+    )
+}
+
+fn create_if_missing(path: &Path, contents: &str) {
+    if !path.exists() {
+        fs::write(path, contents).unwrap();
+        println!("created {}", path.display());
+    }
+}
+
+fn update(path: &Path, register: impl FnOnce(&str) -> String) {
+    let contents = fs::read_to_string(path).unwrap();
+    fs::write(path, register(&contents)).unwrap();
+}
+
+fn new_day(root: &Path, year: u16, package: &str, name: &str) {
+    let module_path = root.join(format!("src/day{}.rs", name));
+
+    if module_path.exists() {
+        panic!("{} already exists", module_path.display());
+    }
+
+    fs::write(&module_path, render_module(name)).unwrap();
+    println!("created {}", module_path.display());
+
+    create_if_missing(&root.join(format!("inputs/day{}.txt", name)), "");
+    create_if_missing(&root.join(format!("test_inputs/day{}.txt", name)), "");
+
+    update(&root.join("src/lib.rs"), |lib_rs| {
+        register_module(lib_rs, name)
+    });
+    update(&root.join("src/registry.rs"), |registry_rs| {
+        register_day(registry_rs, name)
+    });
+
+    // the fuzz crate sits next to the years and gets a target per day
+    let fuzz = root.parent().unwrap().join("fuzz");
+    if fuzz.join("Cargo.toml").exists() {
+        let target = format!("y{}_day{}", year, name);
+        create_if_missing(
+            &fuzz.join(format!("fuzz_targets/{}.rs", target)),
+            &render_fuzz_target(package, name),
+        );
+        update(&fuzz.join("Cargo.toml"), |fuzz_toml| {
+            register_fuzz_target(fuzz_toml, &target)
+        });
+    }
+
+    println!("registered {} day {}", year, name);
+}
+
+fn new_year(workspace: &Path, year: u16) {
+    let dir = format!("y{}", year);
+    let package = format!("aoc-{}", dir);
+    let root = workspace.join(&dir);
+
+    if root.exists() {
+        panic!("{} already exists", root.display());
+    }
+
+    for sub in ["src", "inputs", "test_inputs"] {
+        fs::create_dir_all(root.join(sub)).unwrap();
+    }
+    let year_str = year.to_string();
+    fs::write(
+        root.join("Cargo.toml"),
+        YEAR_MANIFEST.replace("YYYY", &year_str),
+    )
+    .unwrap();
+    fs::write(root.join("src/lib.rs"), YEAR_LIB.replace("YYYY", &year_str)).unwrap();
+    fs::write(root.join("src/registry.rs"), YEAR_REGISTRY).unwrap();
+    println!("created {}", root.display());
+
+    update(&workspace.join("Cargo.toml"), |workspace_toml| {
+        register_member(workspace_toml, &dir)
+    });
+    update(&workspace.join("aoc/Cargo.toml"), |cargo_toml| {
+        register_dependency(cargo_toml, &package, &dir)
+    });
+    update(&workspace.join("aoc/src/main.rs"), |main_rs| {
+        register_year(main_rs, &package)
+    });
+    if workspace.join("fuzz/Cargo.toml").exists() {
+        update(&workspace.join("fuzz/Cargo.toml"), |cargo_toml| {
+            register_dependency(cargo_toml, &package, &dir)
+        });
+    }
+
+    println!("registered {}", year);
+
+    // the registry imports go unused in a year without days, so it starts out with day 01
+    new_day(&root, year, &package, "01");
+}
+
+pub fn main(year: &Year, mut args: impl Iterator<Item = String>) {
+    const USAGE: &str = "usage: new <day> | new year <year>";
+
+    let root = Path::new(year.root);
+
+    match args.next().as_deref() {
+        Some("year") => {
+            let new = args
+                .next()
+                .and_then(|s| s.parse::<u16>().ok())
+                .filter(|year| (2015..=9999).contains(year))
+                .expect(USAGE);
+            new_year(root.parent().unwrap(), new);
+        }
+        day => {
+            let day = day
+                .and_then(|s| s.parse::<u8>().ok())
+                .filter(|day| (1..=99).contains(day))
+                .expect(USAGE);
+            new_day(root, year.year, year.package, &format!("{:02}", day));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_module() {
+        let module = render_module("26");
+        assert!(module.contains("include_bytes!(\"../inputs/day26.txt\")"));
+        assert!(module.contains("include_bytes!(\"../test_inputs/day26.txt\")"));
+        assert!(!module.contains("NN"));
+    }
+
+    #[test]
+    fn test_register_module() {
+        let lib_rs = "pub mod day01;\npub mod day03;\nmod registry;\n";
+        assert_eq!(
+            register_module(lib_rs, "02"),
+            "pub mod day01;\npub mod day02;\npub mod day03;\nmod registry;\n"
+        );
+        assert_eq!(
+            register_module(lib_rs, "04"),
+            "pub mod day01;\npub mod day03;\npub mod day04;\nmod registry;\n"
+        );
+
+        let lib_rs = YEAR_LIB.replace("YYYY", "2025");
+        assert!(register_module(&lib_rs, "01").contains("\npub mod day01;\nmod registry;\n"));
+        assert!(!lib_rs.contains("YYYY"));
+    }
+
+    #[test]
+    fn test_register_day() {
+        let registry_rs =
+            "pub static DAYS: &[Day] = &[\n    Day {\n        name: \"01\",\n    },\n];\n";
+        let registered = register_day(registry_rs, "02");
+        assert!(registered.ends_with(
+            "    Day {\n        name: \"02\",\n        input: day02::INPUT,\n        parse: parse!(bytes: day02::parse),\n        parts: parts!(bytes: day02::part1, day02::part2),\n    },\n];\n"
+        ));
+    }
+
+    #[test]
+    fn test_register_fresh_year() {
+        let registered = register_day(YEAR_REGISTRY, "01");
+        assert!(registered.contains("&[\n    Day {\n        name: \"01\","));
+        assert!(registered.contains("\n];\n\npub fn commands"));
+    }
+
+    #[test]
+    fn test_fuzz_target() {
+        assert_eq!(
+            render_fuzz_target("aoc-y2024", "26"),
+            "#![no_main]\n\nuse libfuzzer_sys::fuzz_target;\n\nfuzz_target!(|input: &[u8]| aoc_fuzz::solve(&aoc_y2024::YEAR, \"26\", input));\n"
+        );
+
+        let fuzz_toml = "[[bin]]\nname = \"y2024_day25\"\nbench = false\n";
+        assert_eq!(
+            register_fuzz_target(fuzz_toml, "y2024_day26"),
+            "[[bin]]\nname = \"y2024_day25\"\nbench = false\n\n[[bin]]\nname = \"y2024_day26\"\npath = \"fuzz_targets/y2024_day26.rs\"\ntest = false\ndoc = false\nbench = false\n"
+        );
+    }
+
+    #[test]
+    fn test_register_year() {
+        assert_eq!(
+            register_member(
+                "[workspace]\nmembers = [\"aoc\", \"y2024\"]\nexclude = [\"fuzz\"]\n",
+                "y2025"
+            ),
+            "[workspace]\nmembers = [\"aoc\", \"y2024\", \"y2025\"]\nexclude = [\"fuzz\"]\n"
+        );
+        assert_eq!(
+            register_dependency(
+                "[dependencies]\naoc-y2024 = { path = \"../y2024\" }\n\n[dev-dependencies]\n",
+                "aoc-y2025",
+                "y2025"
+            ),
+            "[dependencies]\naoc-y2024 = { path = \"../y2024\" }\naoc-y2025 = { path = \"../y2025\" }\n\n[dev-dependencies]\n"
+        );
+        assert_eq!(
+            register_year("    aoc_core::main(&[&aoc_y2024::YEAR]);\n", "aoc-y2025"),
+            "    aoc_core::main(&[&aoc_y2024::YEAR, &aoc_y2025::YEAR]);\n"
+        );
+    }
+}
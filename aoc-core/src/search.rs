@@ -0,0 +1,140 @@
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+
+// steps from start to every node it reaches, next gives the neighbours of a node
+pub fn bfs<N, I>(start: N, mut next: impl FnMut(&N) -> I) -> HashMap<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut dist = HashMap::from([(start.clone(), 0)]);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(node) = queue.pop_front() {
+        let steps = dist[&node] + 1;
+        for n in next(&node) {
+            if let Entry::Vacant(entry) = dist.entry(n.clone()) {
+                entry.insert(steps);
+                queue.push_back(n);
+            }
+        }
+    }
+
+    dist
+}
+
+// lowest cost from start to the first node that is a goal, next gives the neighbours of a node
+// with the cost of getting there
+pub fn dijkstra<N, I>(
+    start: N,
+    mut next: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<usize>
+where
+    N: Clone + Eq + Hash + Ord,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    let mut dist = HashMap::from([(start.clone(), 0)]);
+    let mut heap = BinaryHeap::from([Reverse((0, start))]);
+
+    while let Some(Reverse((cost, node))) = heap.pop() {
+        if is_goal(&node) {
+            return Some(cost);
+        }
+        if dist.get(&node).is_some_and(|&d| cost > d) {
+            continue;
+        }
+
+        for (n, step) in next(&node) {
+            let cost = cost + step;
+            if dist.get(&n).is_none_or(|&d| cost < d) {
+                dist.insert(n.clone(), cost);
+                heap.push(Reverse((cost, n)));
+            }
+        }
+    }
+
+    None
+}
+
+fn count<N, I>(
+    node: &N,
+    next: &mut impl FnMut(&N) -> I,
+    is_goal: &mut impl FnMut(&N) -> bool,
+    memo: &mut HashMap<N, usize>,
+) -> usize
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    if is_goal(node) {
+        return 1;
+    }
+    if let Some(&paths) = memo.get(node) {
+        return paths;
+    }
+
+    let paths = next(node)
+        .into_iter()
+        .map(|n| count(&n, next, is_goal, memo))
+        .sum();
+    memo.insert(node.clone(), paths);
+    paths
+}
+
+// distinct paths from start that end at the first goal on the way, next must not lead in circles
+pub fn count_paths<N, I>(
+    start: N,
+    mut next: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> usize
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    count(&start, &mut next, &mut is_goal, &mut HashMap::new())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0 -> 1 -> 3, 0 -> 2 -> 3, 3 -> 4, with 2 -> 3 being expensive
+    fn next(&node: &usize) -> Vec<usize> {
+        match node {
+            0 => vec![1, 2],
+            1 | 2 => vec![3],
+            3 => vec![4],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn test_bfs() {
+        let dist = bfs(0, next);
+        assert_eq!(dist.len(), 5);
+        assert_eq!((dist[&0], dist[&2], dist[&4]), (0, 1, 3));
+        assert_eq!(bfs(4, next), HashMap::from([(4, 0)]));
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let weighted = |&node: &usize| {
+            next(&node)
+                .into_iter()
+                .map(move |n| (n, if node == 2 { 10 } else { n }))
+        };
+        assert_eq!(dijkstra(0, weighted, |&n| n == 3), Some(4));
+        assert_eq!(dijkstra(0, weighted, |&n| n == 2), Some(2));
+        assert_eq!(dijkstra(4, weighted, |&n| n == 0), None);
+    }
+
+    #[test]
+    fn test_count_paths() {
+        assert_eq!(count_paths(0, next, |&n| n == 4), 2);
+        assert_eq!(count_paths(0, next, |&n| n == 1), 1);
+        assert_eq!(count_paths(0, next, |&n| n == 5), 0);
+    }
+}
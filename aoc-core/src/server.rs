@@ -0,0 +1,315 @@
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::panic::{self, AssertUnwindSafe};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::cancel;
+use crate::output::{Answer, json_answer, json_string};
+use crate::registry::Year;
+
+const DEFAULT_PORT: u16 = 8080;
+// generous for puzzle inputs, the body is read before anything else is checked
+const MAX_BODY: usize = 16 << 20;
+// the request line and all headers together
+const MAX_HEAD: usize = 16 << 10;
+// per read or write, so an idle client can't hold on to its thread
+const IO_TIMEOUT: Duration = Duration::from_secs(30);
+
+struct Response {
+    status: u16,
+    body: String,
+}
+
+impl Response {
+    fn error(status: u16, message: &str) -> Self {
+        Self {
+            status,
+            body: format!("{{\"error\":{}}}", json_string(message)),
+        }
+    }
+
+    fn reason(&self) -> &'static str {
+        match self.status {
+            200 => "OK",
+            400 => "Bad Request",
+            404 => "Not Found",
+            405 => "Method Not Allowed",
+            413 => "Payload Too Large",
+            431 => "Request Header Fields Too Large",
+            503 => "Service Unavailable",
+            _ => "Internal Server Error",
+        }
+    }
+}
+
+fn handle(
+    years: &[&Year],
+    timeout: Option<Duration>,
+    method: &str,
+    path: &str,
+    body: &[u8],
+) -> Response {
+    let segments = path.trim_matches('/').split('/').collect::<Vec<&str>>();
+
+    let (year, day, part) = match segments[..] {
+        ["day", day, "part", part] => (None, day, part),
+        ["year", year, "day", day, "part", part] => (Some(year), day, part),
+        _ => return Response::error(404, "unknown route"),
+    };
+
+    if method != "POST" {
+        return Response::error(405, "only POST is supported");
+    }
+
+    let Some(year) = (match year {
+        Some(year) => years.iter().find(|y| y.year.to_string() == year),
+        None => years.last(),
+    }) else {
+        return Response::error(404, "unknown year");
+    };
+    let Some(day) = year.day(day) else {
+        return Response::error(404, "unknown day");
+    };
+    let Some((part, &solver)) = part
+        .parse::<usize>()
+        .ok()
+        .and_then(|n| Some((n, day.parts.get(n.checked_sub(1)?)?)))
+    else {
+        return Response::error(404, "unknown part");
+    };
+
+    let input = body.trim_ascii_end().to_vec();
+    let start = Instant::now();
+    let answer = panic::catch_unwind(AssertUnwindSafe(|| {
+        cancel::with_timeout(timeout, move |cancel| solver(&input, cancel))
+    }));
+    let elapsed = start.elapsed();
+
+    match answer {
+        Ok(Ok(answer)) => Response {
+            status: 200,
+            body: json_answer(&Answer {
+                year: year.year,
+                day: day.name,
+                part,
+                answer: &answer,
+                elapsed,
+            }),
+        },
+        Ok(Err(_)) => Response::error(503, "solver timed out"),
+        Err(_) => Response::error(500, "solver panicked"),
+    }
+}
+
+fn read_request(stream: impl Read) -> Result<(String, String, Vec<u8>), Response> {
+    let malformed = || Response::error(400, "malformed request");
+    let mut reader = BufReader::new(stream);
+    let mut head = reader.by_ref().take(MAX_HEAD as u64);
+
+    // a line without its newline either ran into the end of the stream or the size limit
+    let mut read_line = |line: &mut String| match head.read_line(line) {
+        Ok(_) if line.ends_with('\n') => Ok(()),
+        Ok(_) if head.limit() == 0 => Err(Response::error(431, "request header too large")),
+        _ => Err(malformed()),
+    };
+
+    let mut request_line = String::new();
+    read_line(&mut request_line)?;
+    let mut iter = request_line.split_ascii_whitespace();
+    let method = iter.next().ok_or_else(malformed)?.to_string();
+    let path = iter.next().ok_or_else(malformed)?.to_string();
+
+    let mut content_length = 0;
+    loop {
+        let mut header = String::new();
+        read_line(&mut header)?;
+        let header = header.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':')
+            && name.eq_ignore_ascii_case("content-length")
+        {
+            content_length = value.trim().parse().map_err(|_| malformed())?;
+        }
+    }
+
+    if content_length > MAX_BODY {
+        return Err(Response::error(413, "request body too large"));
+    }
+
+    // the header is untrusted, so the buffer only grows as the body actually arrives
+    let mut body = vec![];
+    reader
+        .take(content_length as u64)
+        .read_to_end(&mut body)
+        .map_err(|_| malformed())?;
+    if body.len() != content_length {
+        return Err(malformed());
+    }
+
+    Ok((method, path, body))
+}
+
+fn serve_connection(years: &[&Year], timeout: Option<Duration>, mut stream: TcpStream) {
+    let _ = stream.set_read_timeout(Some(IO_TIMEOUT));
+    let _ = stream.set_write_timeout(Some(IO_TIMEOUT));
+
+    let response = match read_request(&stream) {
+        Ok((method, path, body)) => handle(years, timeout, &method, &path, &body),
+        Err(response) => response,
+    };
+
+    let _ = write!(
+        stream,
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        response.status,
+        response.reason(),
+        response.body.len(),
+        response.body
+    );
+}
+
+pub fn main(
+    years: &[&'static Year],
+    timeout: Option<Duration>,
+    mut args: impl Iterator<Item = String>,
+) {
+    let mut port = DEFAULT_PORT;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--port" => {
+                port = args
+                    .next()
+                    .and_then(|s| s.parse().ok())
+                    .expect("invalid value for --port")
+            }
+            _ => panic!("invalid argument for \"serve\": {}", arg),
+        }
+    }
+
+    let listener = TcpListener::bind(("127.0.0.1", port)).unwrap();
+    println!("listening on http://127.0.0.1:{}", port);
+
+    let years = years.to_vec();
+
+    for stream in listener.incoming().flatten() {
+        let years = years.clone();
+        thread::spawn(move || serve_connection(&years, timeout, stream));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::Day;
+
+    static YEAR: Year = Year {
+        year: 2024,
+        package: "",
+        root: "",
+        days: &[Day {
+            name: "01",
+            input: b"",
+            parse: |_| Ok(()),
+            parts: &[
+                |input, _| Ok(input.len().to_string()),
+                |_, _| panic!(),
+                |_, cancel| loop {
+                    cancel.check()?;
+                },
+            ],
+        }],
+        commands: |_| &[],
+    };
+
+    #[test]
+    fn test_handle() {
+        let years = [&YEAR];
+
+        let response = handle(&years, None, "POST", "/day/1/part/1", b"abc\n");
+        assert_eq!(response.status, 200);
+        assert!(response.body.starts_with(
+            "{\"year\":2024,\"day\":\"01\",\"part\":1,\"answer\":\"3\",\"type\":\"integer\","
+        ));
+
+        assert_eq!(
+            handle(&years, None, "POST", "/year/2024/day/01/part/1", b"").status,
+            200
+        );
+        assert_eq!(
+            handle(&years, None, "POST", "/year/2023/day/1/part/1", b"").status,
+            404
+        );
+        assert_eq!(
+            handle(&years, None, "GET", "/day/1/part/1", b"").status,
+            405
+        );
+        assert_eq!(
+            handle(&years, None, "POST", "/day/2/part/1", b"").status,
+            404
+        );
+        assert_eq!(
+            handle(&years, None, "POST", "/day/1/part/0", b"").status,
+            404
+        );
+        assert_eq!(
+            handle(&years, None, "POST", "/day/1/part/4", b"").status,
+            404
+        );
+        assert_eq!(
+            handle(&years, None, "POST", "/day/1/part/2", b"").status,
+            500
+        );
+        assert_eq!(handle(&years, None, "POST", "/days", b"").status, 404);
+
+        let timeout = Some(Duration::from_millis(10));
+        assert_eq!(
+            handle(&years, timeout, "POST", "/day/1/part/3", b"").status,
+            503
+        );
+    }
+
+    #[test]
+    fn test_read_request() {
+        let (method, path, body) =
+            read_request(&b"POST /day/1/part/1 HTTP/1.1\r\nContent-Length: 4\r\n\r\nabc\n"[..])
+                .ok()
+                .unwrap();
+        assert_eq!((method.as_str(), path.as_str()), ("POST", "/day/1/part/1"));
+        assert_eq!(body, b"abc\n");
+
+        let status = |request: &[u8]| read_request(request).err().map(|r| r.status);
+        assert_eq!(
+            status(b"POST /day/1/part/1 HTTP/1.1\r\nContent-Length: 99999999999999\r\n\r\nabc"),
+            Some(413)
+        );
+        assert_eq!(
+            status(
+                b"POST /day/1/part/1 HTTP/1.1\r\nContent-Length: 99999999999999999999999\r\n\r\n"
+            ),
+            Some(400)
+        );
+        assert_eq!(
+            status(b"POST /day/1/part/1 HTTP/1.1\r\nContent-Length: 10\r\n\r\nabc"),
+            Some(400)
+        );
+        assert_eq!(status(b""), Some(400));
+        assert_eq!(
+            status(b"POST /day/1/part/1 HTTP/1.1\r\nContent-Length: 4"),
+            Some(400)
+        );
+
+        let mut endless_header = b"POST /day/1/part/1 HTTP/1.1\r\nX-Padding: ".to_vec();
+        endless_header.resize(MAX_HEAD * 2, b'a');
+        assert_eq!(status(&endless_header), Some(431));
+
+        let mut many_headers = b"POST /day/1/part/1 HTTP/1.1\r\n".to_vec();
+        while many_headers.len() < MAX_HEAD * 2 {
+            many_headers.extend_from_slice(b"X-Padding: a\r\n");
+        }
+        assert_eq!(status(&many_headers), Some(431));
+    }
+}
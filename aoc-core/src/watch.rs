@@ -0,0 +1,145 @@
+use std::collections::HashMap;
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use crate::cancel::Cancel;
+use crate::registry::{Day, Year};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+fn watched_files(root: &Path, name: &str) -> Vec<PathBuf> {
+    let mut files = vec![root.join(format!("inputs/day{}.txt", name))];
+
+    let prefix = format!("day{}", name);
+    if let Ok(entries) = fs::read_dir(root.join("test_inputs")) {
+        let mut examples = entries
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| {
+                path.file_name()
+                    .and_then(|name| name.to_str())
+                    .is_some_and(|name| name.starts_with(&prefix) && name.ends_with(".txt"))
+            })
+            .collect::<Vec<PathBuf>>();
+        examples.sort();
+        files.extend(examples);
+    }
+
+    files
+}
+
+fn modified(files: &[PathBuf]) -> Vec<Option<SystemTime>> {
+    files
+        .iter()
+        .map(|path| fs::metadata(path).and_then(|m| m.modified()).ok())
+        .collect()
+}
+
+fn describe(answer: &str, previous: Option<&String>) -> String {
+    match previous {
+        None => answer.to_string(),
+        Some(previous) if previous == answer => format!("{} (unchanged)", answer),
+        Some(previous) => format!("{} (was {})", answer, previous),
+    }
+}
+
+fn solve(day: &Day, files: &[PathBuf], answers: &mut HashMap<(PathBuf, usize), String>) {
+    for path in files {
+        let Ok(input) = fs::read(path) else {
+            continue;
+        };
+        let input = input.trim_ascii_end();
+
+        println!("{}", path.display());
+        for (ix, part) in day.parts.iter().enumerate() {
+            let answer =
+                match panic::catch_unwind(AssertUnwindSafe(|| part(input, &Cancel::default()))) {
+                    Ok(Ok(answer)) => answer,
+                    Ok(Err(err)) => err.to_string(),
+                    Err(_) => "panicked".to_string(),
+                };
+            let key = (path.clone(), ix + 1);
+            println!(
+                "  part {}: {}",
+                ix + 1,
+                describe(&answer, answers.get(&key))
+            );
+            answers.insert(key, answer);
+        }
+    }
+}
+
+fn check_examples(year: &Year, name: &str) {
+    let status = Command::new(env!("CARGO"))
+        .current_dir(year.root)
+        .args(["test", "--quiet", "--package", year.package])
+        .arg(format!("day{}::tests::test_", name))
+        .status();
+
+    match status {
+        Ok(status) if status.success() => println!("example checks passed"),
+        Ok(_) => println!("example checks failed"),
+        Err(err) => println!("could not run example checks: {}", err),
+    }
+}
+
+pub fn main(year: &Year, mut args: impl Iterator<Item = String>) {
+    let name = args.next().expect("usage: watch <day>");
+    let day = year.expect_day(&name);
+    let root = Path::new(year.root);
+
+    let mut answers = HashMap::new();
+    let mut last_modified = vec![];
+
+    loop {
+        let files = watched_files(root, day.name);
+        let current = modified(&files);
+
+        if current != last_modified {
+            println!("==== {} day {} ====", year.year, day.name);
+            solve(day, &files, &mut answers);
+            check_examples(year, day.name);
+            last_modified = current;
+        }
+
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn test_watched_files() {
+        let root = env::temp_dir().join(format!("aoc-watch-{}", std::process::id()));
+        fs::create_dir_all(root.join("test_inputs")).unwrap();
+        for name in ["day03_part1.txt", "day03_part2.txt", "day04.txt"] {
+            fs::write(root.join("test_inputs").join(name), "").unwrap();
+        }
+
+        let files = watched_files(&root, "03");
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(
+            files,
+            [
+                root.join("inputs/day03.txt"),
+                root.join("test_inputs/day03_part1.txt"),
+                root.join("test_inputs/day03_part2.txt"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_describe() {
+        assert_eq!(describe("42", None), "42");
+        assert_eq!(describe("42", Some(&"42".to_string())), "42 (unchanged)");
+        assert_eq!(describe("42", Some(&"41".to_string())), "42 (was 41)");
+    }
+}
@@ -0,0 +1,3 @@
+fn main() {
+    aoc_core::main(&[&aoc_y2024::YEAR]);
+}
@@ -0,0 +1,23 @@
+use std::process::Command;
+
+use serde_json::Value;
+
+// solvers share stdout with the report, so anything they print would end up inside the array
+#[test]
+fn test_json_output() {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["--format", "json", "--timeout", "10"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+
+    let answers: Vec<Value> = serde_json::from_str(&stdout).unwrap();
+    assert!(!answers.is_empty());
+    for answer in &answers {
+        assert_eq!(answer["year"], 2024);
+        assert!(answer["day"].is_string());
+        assert!(answer["part"].is_u64());
+        assert!(answer["answer"].is_string());
+        assert!(answer["time_ns"].is_u64());
+    }
+}
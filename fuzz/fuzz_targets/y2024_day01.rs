@@ -0,0 +1,5 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &[u8]| aoc_fuzz::solve(&aoc_y2024::YEAR, "01", input));
@@ -0,0 +1,43 @@
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::Duration;
+
+use aoc_core::cancel::Cancel;
+use aoc_core::registry::Year;
+
+const TIME_LIMIT: Duration = Duration::from_secs(10);
+
+pub fn solve(year: &Year, name: &str, input: &[u8]) {
+    let day = year.expect_day(name);
+    let input = input.trim_ascii_end();
+
+    // parsing has to reject bad input without panicking, the parts only get to see what it accepts
+    if (day.parse)(input).is_err() {
+        return;
+    }
+
+    for (ix, &part) in day.parts.iter().enumerate() {
+        let (tx, rx) = mpsc::channel();
+        let input = input.to_vec();
+
+        thread::spawn(move || {
+            let _ = tx.send(part(&input, &Cancel::default()));
+        });
+
+        match rx.recv_timeout(TIME_LIMIT) {
+            Ok(_) => {}
+            Err(RecvTimeoutError::Timeout) => {
+                panic!(
+                    "{} day {} part {} exceeded {:?}",
+                    year.year,
+                    day.name,
+                    ix + 1,
+                    TIME_LIMIT
+                )
+            }
+            Err(RecvTimeoutError::Disconnected) => {
+                panic!("{} day {} part {} panicked", year.year, day.name, ix + 1)
+            }
+        }
+    }
+}
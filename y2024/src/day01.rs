@@ -0,0 +1,308 @@
+extern crate test;
+
+use std::collections::HashMap;
+use std::fmt;
+
+use aoc_core::parse::{ParseError, integer};
+
+pub const INPUT: &[u8] = include_bytes!("../inputs/day01.txt");
+
+// one vec per column, lines may use any amount of whitespace between values but all of them need
+// the same number of values; 32 bit values, so distances and their sum can't overflow
+pub fn parse_columns(input: &[u8]) -> Result<Vec<Vec<i64>>, ParseError> {
+    let mut columns: Vec<Vec<i64>> = Vec::new();
+
+    for (row, line) in input.split(|c| *c == b'\n').enumerate() {
+        let values = line
+            .split(|c| c.is_ascii_whitespace())
+            .filter(|s| !s.is_empty())
+            .map(|value| integer::<i32>(value).map(i64::from))
+            .collect::<Result<Vec<i64>, ParseError>>()?;
+
+        if row == 0 {
+            columns.resize(values.len(), Vec::new());
+        } else if values.len() != columns.len() {
+            return Err(ParseError::RaggedRow(row));
+        }
+
+        for (column, value) in columns.iter_mut().zip(values) {
+            column.push(value);
+        }
+    }
+
+    Ok(columns)
+}
+
+// the left and right list
+pub fn parse(input: &[u8]) -> Result<(Vec<i64>, Vec<i64>), ParseError> {
+    let mut columns = parse_columns(input)?.into_iter();
+    let left = columns.next().ok_or(ParseError::Empty)?;
+    let right = columns.next().ok_or(ParseError::Missing("right list"))?;
+    Ok((left, right))
+}
+
+pub fn distance(left: &[i64], right: &[i64]) -> u64 {
+    let mut left = left.to_vec();
+    let mut right = right.to_vec();
+
+    left.sort_unstable();
+    right.sort_unstable();
+
+    left.iter().zip(&right).map(|(a, b)| a.abs_diff(*b)).sum()
+}
+
+fn counts(values: &[i64]) -> HashMap<i64, usize> {
+    let mut counts = HashMap::new();
+    for &x in values {
+        *counts.entry(x).or_insert(0) += 1;
+    }
+    counts
+}
+
+pub fn similarity(left: &[i64], right: &[i64]) -> i64 {
+    let counts = counts(right);
+    left.iter()
+        .map(|x| x * counts.get(x).copied().unwrap_or(0) as i64)
+        .sum()
+}
+
+const HISTOGRAM_BUCKETS: u64 = 10;
+const SHARED_VALUES: usize = 5;
+
+#[derive(Debug, PartialEq)]
+pub struct Shared {
+    pub value: i64,
+    pub left: usize,
+    pub right: usize,
+    pub contribution: i64,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Report {
+    // (left, right, distance) after sorting both lists
+    pub pairs: Vec<(i64, i64, u64)>,
+    pub distance: u64,
+    pub similarity: i64,
+    pub min: u64,
+    pub max: u64,
+    pub median: f64,
+    // (bucket start, bucket end, count), ends are inclusive
+    pub histogram: Vec<(u64, u64, usize)>,
+    pub shared: Vec<Shared>,
+}
+
+pub fn report(left: &[i64], right: &[i64]) -> Report {
+    let mut left = left.to_vec();
+    let mut right = right.to_vec();
+
+    left.sort_unstable();
+    right.sort_unstable();
+
+    let pairs = left
+        .iter()
+        .zip(&right)
+        .map(|(&a, &b)| (a, b, a.abs_diff(b)))
+        .collect::<Vec<(i64, i64, u64)>>();
+
+    let mut distances = pairs.iter().map(|p| p.2).collect::<Vec<u64>>();
+    distances.sort_unstable();
+
+    let min = distances.first().copied().unwrap_or(0);
+    let max = distances.last().copied().unwrap_or(0);
+    let median = match distances.len() {
+        0 => 0.0,
+        n if n % 2 == 0 => (distances[n / 2 - 1] + distances[n / 2]) as f64 / 2.0,
+        n => distances[n / 2] as f64,
+    };
+
+    let width = (max - min) / HISTOGRAM_BUCKETS + 1;
+    let mut histogram = (0..HISTOGRAM_BUCKETS)
+        .map(|ix| (min + ix * width, min + (ix + 1) * width - 1, 0))
+        .take_while(|&(start, _, _)| start <= max)
+        .collect::<Vec<(u64, u64, usize)>>();
+    for d in &distances {
+        histogram[((d - min) / width) as usize].2 += 1;
+    }
+
+    let left_counts = counts(&left);
+    let right_counts = counts(&right);
+
+    let mut shared = left_counts
+        .iter()
+        .filter_map(|(&value, &l)| {
+            let r = *right_counts.get(&value)?;
+            Some(Shared {
+                value,
+                left: l,
+                right: r,
+                contribution: value * (l * r) as i64,
+            })
+        })
+        .collect::<Vec<Shared>>();
+    shared.sort_unstable_by_key(|s| (std::cmp::Reverse(s.left * s.right), s.value));
+    shared.truncate(SHARED_VALUES);
+
+    Report {
+        distance: distances.iter().sum(),
+        similarity: similarity(&left, &right),
+        pairs,
+        min,
+        max,
+        median,
+        histogram,
+        shared,
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "pairs: {}", self.pairs.len())?;
+        writeln!(f, "total distance: {}", self.distance)?;
+        writeln!(f, "similarity score: {}", self.similarity)?;
+        writeln!(
+            f,
+            "distance min/median/max: {}/{}/{}",
+            self.min, self.median, self.max
+        )?;
+
+        writeln!(f, "histogram:")?;
+        let widest = self.histogram.iter().map(|h| h.2).max().unwrap_or(0);
+        for &(start, end, count) in &self.histogram {
+            let bar = "#".repeat((count * 40).div_ceil(widest.max(1)));
+            let line = format!("  {:>8}..={:<8} {:>6} {}", start, end, count, bar);
+            writeln!(f, "{}", line.trim_end())?;
+        }
+
+        write!(f, "most frequent shared values:")?;
+        for s in &self.shared {
+            write!(
+                f,
+                "\n  {} (left x{}, right x{}) contributes {} ({:.1}%)",
+                s.value,
+                s.left,
+                s.right,
+                s.contribution,
+                100.0 * s.contribution as f64 / self.similarity.max(1) as f64
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+pub fn part1(input: &[u8]) -> u64 {
+    let (left, right) = parse(input).unwrap();
+    distance(&left, &right)
+}
+
+pub fn part2(input: &[u8]) -> i64 {
+    let (left, right) = parse(input).unwrap();
+    similarity(&left, &right)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test::Bencher;
+
+    const TEST_INPUT: &[u8] = include_bytes!("../test_inputs/day01.txt");
+
+    #[test]
+    fn test_part1() {
+        let input = TEST_INPUT.trim_ascii_end();
+        assert_eq!(part1(input), 11);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = TEST_INPUT.trim_ascii_end();
+        assert_eq!(part2(input), 31);
+    }
+
+    #[test]
+    fn test_columns() {
+        let columns = parse_columns(b"3 4\t1\n4  3 -2\n\t2 5 3").unwrap();
+        assert_eq!(columns, vec![vec![3, 4, 2], vec![4, 3, 5], vec![1, -2, 3]]);
+        assert_eq!(distance(&columns[0], &columns[2]), 2 + 4 + 1);
+        assert_eq!(similarity(&columns[1], &columns[0]), 4 + 3);
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(parse(b"3 4\n1 2"), Ok((vec![3, 1], vec![4, 2])));
+        assert_eq!(parse(b""), Err(ParseError::Empty));
+        assert_eq!(parse(b"3\n1"), Err(ParseError::Missing("right list")));
+        assert_eq!(parse(b"3 x"), Err(ParseError::InvalidByte(b'x')));
+        assert_eq!(parse(b"1 2\n3\n4 5"), Err(ParseError::RaggedRow(1)));
+        assert_eq!(parse(b"1 9999999999"), Err(ParseError::Overflow));
+        assert_eq!(
+            part1(b"-2147483648 2147483647\n-2147483648 2147483647"),
+            2 * u32::MAX as u64
+        );
+    }
+
+    #[test]
+    fn test_report() {
+        let (left, right) = parse(TEST_INPUT.trim_ascii_end()).unwrap();
+        let report = report(&left, &right);
+
+        assert_eq!(
+            report.pairs,
+            vec![
+                (1, 3, 2),
+                (2, 3, 1),
+                (3, 3, 0),
+                (3, 4, 1),
+                (3, 5, 2),
+                (4, 9, 5)
+            ]
+        );
+        assert_eq!((report.distance, report.similarity), (11, 31));
+        assert_eq!((report.min, report.max, report.median), (0, 5, 1.5));
+        assert_eq!(
+            report.histogram,
+            vec![
+                (0, 0, 1),
+                (1, 1, 2),
+                (2, 2, 2),
+                (3, 3, 0),
+                (4, 4, 0),
+                (5, 5, 1)
+            ]
+        );
+        assert_eq!(
+            report.shared,
+            vec![
+                Shared {
+                    value: 3,
+                    left: 3,
+                    right: 3,
+                    contribution: 27
+                },
+                Shared {
+                    value: 4,
+                    left: 1,
+                    right: 1,
+                    contribution: 4
+                },
+            ]
+        );
+        assert!(
+            report
+                .to_string()
+                .contains("3 (left x3, right x3) contributes 27")
+        );
+    }
+
+    #[bench]
+    fn bench_part1(b: &mut Bencher) {
+        let input = INPUT.trim_ascii_end();
+        b.iter(|| part1(input))
+    }
+
+    #[bench]
+    fn bench_part2(b: &mut Bencher) {
+        let input = INPUT.trim_ascii_end();
+        b.iter(|| part2(input))
+    }
+}
@@ -0,0 +1,298 @@
+extern crate test;
+
+use aoc_core::parse::{ParseError, integer};
+
+pub const INPUT: &[u8] = include_bytes!("../inputs/day02.txt");
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rules {
+    pub min_step: u32,
+    pub max_step: u32,
+    pub removals: usize,
+}
+
+pub const PART1: Rules = Rules {
+    min_step: 1,
+    max_step: 3,
+    removals: 0,
+};
+
+pub const PART2: Rules = Rules {
+    removals: 1,
+    ..PART1
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Violation {
+    NotMonotonic,
+    StepTooSmall,
+    StepTooLarge,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Analysis {
+    // first pair of levels (ix, ix + 1) breaking the rules
+    pub violation: Option<(usize, Violation)>,
+    // levels the dampener removes, empty for safe reports and none if it needs too many
+    pub removed: Option<Vec<usize>>,
+}
+
+impl Violation {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Violation::NotMonotonic => "not monotonic",
+            Violation::StepTooSmall => "step too small",
+            Violation::StepTooLarge => "step too large",
+        }
+    }
+}
+
+impl Analysis {
+    pub fn is_safe(&self) -> bool {
+        self.violation.is_none()
+    }
+
+    pub fn is_safe_dampened(&self) -> bool {
+        self.removed.is_some()
+    }
+}
+
+pub fn parse_report(line: &[u8]) -> Result<Vec<u32>, ParseError> {
+    line.split(|c| *c == b' ').map(integer::<u32>).collect()
+}
+
+// one report per line
+pub fn parse(input: &[u8]) -> Result<Vec<Vec<u32>>, ParseError> {
+    input.split(|c| *c == b'\n').map(parse_report).collect()
+}
+
+// the first pair decides whether the report should increase or decrease
+pub fn check(levels: &[u32], rules: &Rules) -> Option<(usize, Violation)> {
+    let decreasing = levels.len() > 1 && levels[1] < levels[0];
+
+    levels.windows(2).enumerate().find_map(|(ix, pair)| {
+        let (a, b) = if decreasing {
+            (pair[1], pair[0])
+        } else {
+            (pair[0], pair[1])
+        };
+
+        match b.checked_sub(a) {
+            None => Some((ix, Violation::NotMonotonic)),
+            Some(step) if step < rules.min_step => Some((ix, Violation::StepTooSmall)),
+            Some(step) if step > rules.max_step => Some((ix, Violation::StepTooLarge)),
+            Some(_) => None,
+        }
+    })
+}
+
+// cost[i] is the fewest removals before i that leave a valid run ending in level i, only
+// the rules.removals + 1 levels before i can precede it without exceeding the budget
+fn removals_in_direction(levels: &[u32], rules: &Rules, decreasing: bool) -> Option<Vec<usize>> {
+    let valid = |a: u32, b: u32| {
+        let step = if decreasing {
+            a.checked_sub(b)
+        } else {
+            b.checked_sub(a)
+        };
+        step.is_some_and(|step| (rules.min_step..=rules.max_step).contains(&step))
+    };
+
+    let n = levels.len();
+    let mut cost = vec![0; n];
+    let mut prev = vec![None; n];
+
+    for i in 0..n {
+        cost[i] = i;
+        for j in i.saturating_sub(rules.removals + 1)..i {
+            if valid(levels[j], levels[i]) && cost[j] + (i - j - 1) <= cost[i] {
+                cost[i] = cost[j] + (i - j - 1);
+                prev[i] = Some(j);
+            }
+        }
+    }
+
+    let last = (0..n).min_by_key(|&i| cost[i] + (n - 1 - i))?;
+    if cost[last] + (n - 1 - last) > rules.removals {
+        return None;
+    }
+
+    let mut kept = vec![false; n];
+    let mut ix = Some(last);
+    while let Some(i) = ix {
+        kept[i] = true;
+        ix = prev[i];
+    }
+
+    Some((0..n).filter(|&i| !kept[i]).collect())
+}
+
+pub fn dampen(levels: &[u32], rules: &Rules) -> Option<Vec<usize>> {
+    if levels.is_empty() {
+        return Some(vec![]);
+    }
+
+    match (
+        removals_in_direction(levels, rules, false),
+        removals_in_direction(levels, rules, true),
+    ) {
+        (Some(a), Some(b)) if b.len() < a.len() => Some(b),
+        (Some(a), _) => Some(a),
+        (None, b) => b,
+    }
+}
+
+pub fn analyze(levels: &[u32], rules: &Rules) -> Analysis {
+    Analysis {
+        violation: check(levels, rules),
+        removed: dampen(levels, rules),
+    }
+}
+
+pub fn explain(input: &[u8], rules: &Rules) -> String {
+    parse(input)
+        .unwrap()
+        .iter()
+        .enumerate()
+        .map(|(ix, levels)| {
+            let analysis = analyze(levels, rules);
+            let mut result = format!("{:>4}: ", ix + 1);
+
+            match analysis.violation {
+                None => result.push_str("safe"),
+                Some((pair, violation)) => result.push_str(&format!(
+                    "unsafe, levels {} and {}: {}",
+                    pair,
+                    pair + 1,
+                    violation.name()
+                )),
+            }
+            match analysis.removed {
+                Some(removed) if !removed.is_empty() => {
+                    result.push_str(&format!(", safe without levels {:?}", removed))
+                }
+                _ => {}
+            }
+
+            result
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+pub fn count_safe(input: &[u8], rules: &Rules) -> usize {
+    parse(input)
+        .unwrap()
+        .iter()
+        .filter(|levels| dampen(levels, rules).is_some())
+        .count()
+}
+
+pub fn part1(input: &[u8]) -> usize {
+    count_safe(input, &PART1)
+}
+
+pub fn part2(input: &[u8]) -> usize {
+    count_safe(input, &PART2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test::Bencher;
+
+    const TEST_INPUT: &[u8] = include_bytes!("../test_inputs/day02.txt");
+
+    #[test]
+    fn test_part1() {
+        let input = TEST_INPUT.trim_ascii_end();
+        assert_eq!(part1(input), 2);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = TEST_INPUT.trim_ascii_end();
+        assert_eq!(part2(input), 4);
+    }
+
+    #[test]
+    fn test_analyze() {
+        let analyses = parse(TEST_INPUT.trim_ascii_end())
+            .unwrap()
+            .iter()
+            .map(|levels| analyze(levels, &PART2))
+            .collect::<Vec<Analysis>>();
+
+        let violations = analyses.iter().map(|a| a.violation).collect::<Vec<_>>();
+        assert_eq!(
+            violations,
+            vec![
+                None,
+                Some((1, Violation::StepTooLarge)),
+                Some((2, Violation::StepTooLarge)),
+                Some((1, Violation::NotMonotonic)),
+                Some((2, Violation::StepTooSmall)),
+                None,
+            ]
+        );
+
+        let removed = analyses.into_iter().map(|a| a.removed).collect::<Vec<_>>();
+        assert_eq!(
+            removed,
+            vec![
+                Some(vec![]),
+                None,
+                None,
+                Some(vec![1]),
+                Some(vec![2]),
+                Some(vec![]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(parse(b"7 6 4\n1"), Ok(vec![vec![7, 6, 4], vec![1]]));
+        assert_eq!(parse(b""), Err(ParseError::Empty));
+        assert_eq!(parse(b"7  6"), Err(ParseError::Empty));
+        assert_eq!(parse(b"7 -6"), Err(ParseError::InvalidByte(b'-')));
+    }
+
+    #[test]
+    fn test_rules() {
+        let rules = Rules {
+            min_step: 1,
+            max_step: 5,
+            removals: 2,
+        };
+        assert_eq!(dampen(&[1, 9, 2, 9, 3, 4], &rules), Some(vec![1, 3]));
+        assert_eq!(dampen(&[1, 9, 9, 9, 3, 4], &rules), None);
+        assert_eq!(dampen(&[10, 5, 1, 1, 0], &rules), Some(vec![2]));
+        assert_eq!(dampen(&[7], &PART1), Some(vec![]));
+
+        let input = TEST_INPUT.trim_ascii_end();
+        let rules = Rules {
+            removals: 2,
+            ..PART1
+        };
+        assert_eq!(count_safe(input, &rules), 6);
+
+        let rules = Rules {
+            max_step: 4,
+            ..PART1
+        };
+        assert_eq!(count_safe(input, &rules), 3);
+    }
+
+    #[bench]
+    fn bench_part1(b: &mut Bencher) {
+        let input = INPUT.trim_ascii_end();
+        b.iter(|| part1(input))
+    }
+
+    #[bench]
+    fn bench_part2(b: &mut Bencher) {
+        let input = INPUT.trim_ascii_end();
+        b.iter(|| part2(input))
+    }
+}
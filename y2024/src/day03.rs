@@ -0,0 +1,432 @@
+extern crate test;
+
+use std::io::{self, Read};
+use std::ops::Range;
+
+use aoc_core::parse::ParseError;
+
+pub const INPUT: &str = include_str!("../inputs/day03.txt");
+
+const CHUNK_SIZE: usize = 64 * 1024;
+const MAX_DIGITS: usize = 3;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Instruction {
+    pub name: &'static str,
+    pub args: usize,
+}
+
+pub const MUL: Instruction = Instruction {
+    name: "mul",
+    args: 2,
+};
+
+pub const DO: Instruction = Instruction {
+    name: "do",
+    args: 0,
+};
+
+pub const DONT: Instruction = Instruction {
+    name: "don't",
+    args: 0,
+};
+
+pub const INSTRUCTIONS: &[&Instruction] = &[&MUL, &DO, &DONT];
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Token {
+    pub instruction: &'static Instruction,
+    pub args: Vec<usize>,
+    pub span: Range<usize>,
+}
+
+impl Instruction {
+    // longest possible match, name(ddd,ddd,...)
+    fn max_len(&self) -> usize {
+        self.name.len() + 2 + self.args * (MAX_DIGITS + 1)
+    }
+
+    // parses the instruction at the start of bytes, returning its arguments and length
+    fn parse(&self, bytes: &[u8]) -> Option<(Vec<usize>, usize)> {
+        let mut ix = self.name.len();
+        if !bytes.starts_with(self.name.as_bytes()) || bytes.get(ix) != Some(&b'(') {
+            return None;
+        }
+        ix += 1;
+
+        let mut args = Vec::with_capacity(self.args);
+        for n in 0..self.args {
+            if n > 0 {
+                if bytes.get(ix) != Some(&b',') {
+                    return None;
+                }
+                ix += 1;
+            }
+
+            let digits = bytes[ix..]
+                .iter()
+                .take(MAX_DIGITS + 1)
+                .take_while(|b| b.is_ascii_digit())
+                .count();
+            if digits == 0 || digits > MAX_DIGITS {
+                return None;
+            }
+
+            args.push(
+                bytes[ix..ix + digits]
+                    .iter()
+                    .fold(0, |acc, b| acc * 10 + (b - b'0') as usize),
+            );
+            ix += digits;
+        }
+
+        if bytes.get(ix) != Some(&b')') {
+            return None;
+        }
+
+        Some((args, ix + 1))
+    }
+}
+
+// reads in chunks and keeps just enough of a tail around to recognise an instruction
+// straddling two chunks, so the input never has to be in memory at once
+pub struct Tokenizer<R: Read> {
+    reader: R,
+    instructions: &'static [&'static Instruction],
+    lookahead: usize,
+    buffer: Vec<u8>,
+    // absolute offset of buffer[0]
+    offset: usize,
+    position: usize,
+    eof: bool,
+}
+
+impl<R: Read> Tokenizer<R> {
+    pub fn new(reader: R, instructions: &'static [&'static Instruction]) -> Self {
+        Self {
+            reader,
+            instructions,
+            lookahead: instructions.iter().map(|i| i.max_len()).max().unwrap_or(0),
+            buffer: Vec::new(),
+            offset: 0,
+            position: 0,
+            eof: false,
+        }
+    }
+
+    fn fill(&mut self) -> io::Result<()> {
+        while !self.eof && self.buffer.len() - self.position < self.lookahead {
+            self.buffer.drain(..self.position);
+            self.offset += self.position;
+            self.position = 0;
+
+            let len = self.buffer.len();
+            self.buffer.resize(len + CHUNK_SIZE, 0);
+            let n = self.reader.read(&mut self.buffer[len..])?;
+            self.buffer.truncate(len + n);
+            self.eof = n == 0;
+        }
+
+        Ok(())
+    }
+}
+
+impl<R: Read> Iterator for Tokenizer<R> {
+    type Item = io::Result<Token>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Err(err) = self.fill() {
+                return Some(Err(err));
+            }
+            if self.position >= self.buffer.len() {
+                return None;
+            }
+
+            let bytes = &self.buffer[self.position..];
+            let start = self.offset + self.position;
+
+            let token = self.instructions.iter().find_map(|&instruction| {
+                let (args, len) = instruction.parse(bytes)?;
+                Some(Token {
+                    instruction,
+                    args,
+                    span: start..start + len,
+                })
+            });
+
+            match token {
+                Some(token) => {
+                    self.position += token.span.len();
+                    return Some(Ok(token));
+                }
+                None => self.position += 1,
+            }
+        }
+    }
+}
+
+pub trait Machine {
+    fn execute(&mut self, token: &Token);
+}
+
+// every mul counts
+#[derive(Default)]
+pub struct Sum {
+    pub total: usize,
+}
+
+// muls only count while enabled, do() and don't() toggle
+pub struct Conditional {
+    pub enabled: bool,
+    pub total: usize,
+}
+
+impl Default for Conditional {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            total: 0,
+        }
+    }
+}
+
+impl Machine for Sum {
+    fn execute(&mut self, token: &Token) {
+        if *token.instruction == MUL {
+            self.total += token.args[0] * token.args[1];
+        }
+    }
+}
+
+impl Machine for Conditional {
+    fn execute(&mut self, token: &Token) {
+        match token.instruction {
+            i if *i == DO => self.enabled = true,
+            i if *i == DONT => self.enabled = false,
+            i if *i == MUL && self.enabled => self.total += token.args[0] * token.args[1],
+            _ => {}
+        }
+    }
+}
+
+pub fn run<R: Read, M: Machine>(reader: R, mut machine: M) -> io::Result<M> {
+    for token in Tokenizer::new(reader, INSTRUCTIONS) {
+        machine.execute(&token?);
+    }
+    Ok(machine)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Style {
+    Ansi,
+    Html,
+}
+
+const RESET: &str = "\x1b[0m";
+
+fn class(token: &Token, enabled: bool) -> &'static str {
+    match token.instruction {
+        i if *i == MUL && enabled => "mul",
+        i if *i == MUL => "mul disabled",
+        i if *i == DO => "do",
+        i if *i == DONT => "dont",
+        _ => "other",
+    }
+}
+
+fn ansi_colour(class: &str) -> &'static str {
+    match class {
+        "mul" => "\x1b[1;32m",
+        "mul disabled" => "\x1b[2;9;31m",
+        "do" => "\x1b[1;36m",
+        "dont" => "\x1b[1;33m",
+        _ => "\x1b[1m",
+    }
+}
+
+fn escape_html(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+// the input with every recognised instruction highlighted, followed by a listing of
+// each instruction with its offset and what it added to the part 2 sum
+pub fn annotate(input: &str, style: Style) -> String {
+    let mut text = String::new();
+    let mut listing = vec![];
+    let mut machine = Conditional::default();
+    let mut last = 0;
+
+    let plain = |s: &str| match style {
+        Style::Ansi => s.to_string(),
+        Style::Html => escape_html(s),
+    };
+
+    for token in Tokenizer::new(input.as_bytes(), INSTRUCTIONS) {
+        let token = token.unwrap();
+        let source = &input[token.span.clone()];
+        let before = machine.total;
+        machine.execute(&token);
+
+        let enabled = *token.instruction != MUL || machine.enabled;
+        let class = class(&token, enabled);
+
+        text.push_str(&plain(&input[last..token.span.start]));
+        match style {
+            Style::Ansi => text.push_str(&format!("{}{}{}", ansi_colour(class), source, RESET)),
+            Style::Html => text.push_str(&format!(
+                "<span class=\"{}\">{}</span>",
+                class,
+                escape_html(source)
+            )),
+        }
+        last = token.span.end;
+
+        let contribution = match class {
+            "mul disabled" => "disabled".to_string(),
+            _ if *token.instruction == MUL => (machine.total - before).to_string(),
+            _ => String::new(),
+        };
+        listing.push(
+            format!("{:>8}  {:<12} {}", token.span.start, source, contribution)
+                .trim_end()
+                .to_string(),
+        );
+    }
+    text.push_str(&plain(&input[last..]));
+    listing.push(format!("total: {}", machine.total));
+
+    match style {
+        Style::Ansi => format!("{}\n\n{}", text, listing.join("\n")),
+        Style::Html => format!(
+            "<pre class=\"memory\">{}</pre>\n<pre class=\"listing\">{}</pre>",
+            text,
+            escape_html(&listing.join("\n"))
+        ),
+    }
+}
+
+// anything that isn't an instruction is noise, so every string is valid
+pub fn parse(input: &str) -> Result<Vec<Token>, ParseError> {
+    // reading from a slice can't fail
+    Ok(Tokenizer::new(input.as_bytes(), INSTRUCTIONS)
+        .map(|token| token.unwrap())
+        .collect())
+}
+
+pub fn part1(input: &str) -> usize {
+    run(input.as_bytes(), Sum::default()).unwrap().total
+}
+
+pub fn part2(input: &str) -> usize {
+    run(input.as_bytes(), Conditional::default()).unwrap().total
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test::Bencher;
+
+    const TEST_INPUT_1: &str = include_str!("../test_inputs/day03_part1.txt");
+    const TEST_INPUT_2: &str = include_str!("../test_inputs/day03_part2.txt");
+
+    #[test]
+    fn test_part1() {
+        let input = TEST_INPUT_1.trim_ascii_end();
+        assert_eq!(part1(input), 161);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = TEST_INPUT_2.trim_ascii_end();
+        assert_eq!(part2(input), 48);
+    }
+
+    #[test]
+    fn test_tokenizer() {
+        let tokens = Tokenizer::new(TEST_INPUT_2.as_bytes(), INSTRUCTIONS)
+            .map(|t| t.unwrap())
+            .map(|t| (t.instruction.name, t.args, t.span))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            tokens,
+            vec![
+                ("mul", vec![2, 4], 1..9),
+                ("don't", vec![], 20..27),
+                ("mul", vec![5, 5], 28..36),
+                ("mul", vec![11, 8], 48..57),
+                ("do", vec![], 59..63),
+                ("mul", vec![8, 5], 64..72),
+            ]
+        );
+
+        assert!(
+            Tokenizer::new(&b"mul(1234,5)mul(1,2"[..], INSTRUCTIONS)
+                .next()
+                .is_none()
+        );
+    }
+
+    // one byte per read forces every instruction to straddle a refill
+    struct Trickle<'a>(&'a [u8]);
+
+    impl Read for Trickle<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let Some((&first, rest)) = self.0.split_first() else {
+                return Ok(0);
+            };
+            buf[0] = first;
+            self.0 = rest;
+            Ok(1)
+        }
+    }
+
+    #[test]
+    fn test_streaming() {
+        let input = TEST_INPUT_2.trim_ascii_end().as_bytes();
+        let machine = run(Trickle(input), Conditional::default()).unwrap();
+        assert_eq!(machine.total, 48);
+    }
+
+    #[test]
+    fn test_annotate() {
+        let input = "mul(2,4)<don't()mul(5,5)do()";
+
+        assert_eq!(
+            annotate(input, Style::Html),
+            [
+                "<pre class=\"memory\"><span class=\"mul\">mul(2,4)</span>&lt;",
+                "<span class=\"dont\">don't()</span>",
+                "<span class=\"mul disabled\">mul(5,5)</span>",
+                "<span class=\"do\">do()</span></pre>\n",
+                "<pre class=\"listing\">       0  mul(2,4)     8\n",
+                "       9  don't()\n",
+                "      16  mul(5,5)     disabled\n",
+                "      24  do()\n",
+                "total: 8</pre>",
+            ]
+            .concat()
+        );
+
+        assert!(
+            annotate(input, Style::Ansi)
+                .starts_with("\x1b[1;32mmul(2,4)\x1b[0m<\x1b[1;33mdon't()\x1b[0m")
+        );
+    }
+
+    #[bench]
+    fn bench_part1(b: &mut Bencher) {
+        let input = INPUT.trim_ascii_end();
+        b.iter(|| part1(input))
+    }
+
+    #[bench]
+    fn bench_part2(b: &mut Bencher) {
+        let input = INPUT.trim_ascii_end();
+        b.iter(|| part2(input))
+    }
+}
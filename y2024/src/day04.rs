@@ -0,0 +1,499 @@
+extern crate test;
+
+use std::collections::{HashMap, VecDeque};
+
+use aoc_core::grid::Grid;
+use aoc_core::parse::ParseError;
+
+pub const INPUT: &[u8] = include_bytes!("../inputs/day04.txt");
+
+const WILDCARD: u8 = b'.';
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+// quarter turns clockwise, applied after mirroring left to right
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Orientation {
+    pub turns: u8,
+    pub mirrored: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pattern(Grid);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WordMatch {
+    pub row: usize,
+    pub col: usize,
+    pub direction: Direction,
+}
+
+// row and col are the top left corner of the oriented pattern
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PatternMatch {
+    pub row: usize,
+    pub col: usize,
+    pub orientation: Orientation,
+}
+
+// aho-corasick automaton, outputs include the words reachable through failure links
+#[derive(Debug)]
+pub struct Trie {
+    children: Vec<HashMap<u8, usize>>,
+    fail: Vec<usize>,
+    outputs: Vec<Vec<usize>>,
+    lengths: Vec<usize>,
+}
+
+impl Trie {
+    pub fn new(words: &[&[u8]]) -> Self {
+        let mut trie = Trie {
+            children: vec![HashMap::new()],
+            fail: vec![0],
+            outputs: vec![vec![]],
+            lengths: words.iter().map(|w| w.len()).collect(),
+        };
+
+        for (ix, word) in words.iter().enumerate().filter(|(_, w)| !w.is_empty()) {
+            let mut node = 0;
+            for &b in word.iter() {
+                node = match trie.children[node].get(&b) {
+                    Some(&next) => next,
+                    None => {
+                        trie.children.push(HashMap::new());
+                        trie.fail.push(0);
+                        trie.outputs.push(vec![]);
+                        let next = trie.children.len() - 1;
+                        trie.children[node].insert(b, next);
+                        next
+                    }
+                };
+            }
+            trie.outputs[node].push(ix);
+        }
+
+        let mut queue = trie.children[0]
+            .values()
+            .cloned()
+            .collect::<VecDeque<usize>>();
+        while let Some(node) = queue.pop_front() {
+            let children = trie.children[node]
+                .iter()
+                .map(|(&b, &child)| (b, child))
+                .collect::<Vec<(u8, usize)>>();
+
+            for (b, child) in children {
+                let mut fail = trie.fail[node];
+                while fail != 0 && !trie.children[fail].contains_key(&b) {
+                    fail = trie.fail[fail];
+                }
+                let fail = trie.children[fail].get(&b).copied().unwrap_or(0);
+
+                trie.fail[child] = fail;
+                let inherited = trie.outputs[fail].clone();
+                trie.outputs[child].extend(inherited);
+                queue.push_back(child);
+            }
+        }
+
+        trie
+    }
+
+    fn step(&self, mut node: usize, b: u8) -> usize {
+        loop {
+            if let Some(&next) = self.children[node].get(&b) {
+                return next;
+            }
+            if node == 0 {
+                return 0;
+            }
+            node = self.fail[node];
+        }
+    }
+}
+
+pub fn find_word(grid: &Grid, word: &[u8], directions: &[Direction]) -> Vec<WordMatch> {
+    let mut result = vec![];
+
+    for row in 0..grid.rows {
+        for col in 0..grid.cols {
+            for &direction in directions {
+                let (dr, dc) = direction.delta();
+                let found = word.iter().enumerate().all(|(ix, &b)| {
+                    let ix = ix as isize;
+                    grid.get((row as isize + ix * dr, col as isize + ix * dc)) == Some(&b)
+                });
+
+                if found {
+                    result.push(WordMatch {
+                        row,
+                        col,
+                        direction,
+                    });
+                }
+            }
+        }
+    }
+
+    result
+}
+
+pub fn find_pattern(
+    grid: &Grid,
+    pattern: &Pattern,
+    orientations: &[Orientation],
+) -> Vec<PatternMatch> {
+    let mut result = vec![];
+    let mut tried: Vec<Pattern> = vec![];
+
+    for &orientation in orientations {
+        // symmetric patterns map onto themselves, each occurrence is only counted once
+        let oriented = pattern.oriented(orientation);
+        if tried.contains(&oriented) || oriented.0.rows > grid.rows || oriented.0.cols > grid.cols {
+            continue;
+        }
+
+        for row in 0..=grid.rows - oriented.0.rows {
+            for col in 0..=grid.cols - oriented.0.cols {
+                if oriented.matches_at(grid, row, col) {
+                    result.push(PatternMatch {
+                        row,
+                        col,
+                        orientation,
+                    });
+                }
+            }
+        }
+        tried.push(oriented);
+    }
+
+    result
+}
+
+// every row, column and diagonal as (cells, direction of travel) in one direction only
+fn lines(grid: &Grid) -> Vec<(Vec<(usize, usize)>, Direction)> {
+    let (rows, cols) = (grid.rows as isize, grid.cols as isize);
+    let walk = |mut row: isize, mut col: isize, direction: Direction| {
+        let (dr, dc) = direction.delta();
+        let mut cells = vec![];
+        while 0 <= row && row < rows && 0 <= col && col < cols {
+            cells.push((row as usize, col as usize));
+            row += dr;
+            col += dc;
+        }
+        (cells, direction)
+    };
+
+    let mut lines = vec![];
+    for row in 0..rows {
+        lines.push(walk(row, 0, Direction::E));
+        lines.push(walk(row, 0, Direction::SE));
+        lines.push(walk(row, cols - 1, Direction::SW));
+    }
+    for col in 0..cols {
+        lines.push(walk(0, col, Direction::S));
+        if col > 0 {
+            lines.push(walk(0, col, Direction::SE));
+        }
+        if col < cols - 1 {
+            lines.push(walk(0, col, Direction::SW));
+        }
+    }
+    lines
+}
+
+// all occurrences of all words in all 8 directions, as (word index, match)
+pub fn find_words(grid: &Grid, words: &[&[u8]]) -> Vec<(usize, WordMatch)> {
+    let trie = Trie::new(words);
+    let mut result = vec![];
+
+    for (mut cells, direction) in lines(grid) {
+        for direction in [direction, direction.opposite()] {
+            let mut node = 0;
+            for (ix, &(row, col)) in cells.iter().enumerate() {
+                node = trie.step(node, grid[(row, col)]);
+                for &word in &trie.outputs[node] {
+                    let (row, col) = cells[ix + 1 - trie.lengths[word]];
+                    result.push((
+                        word,
+                        WordMatch {
+                            row,
+                            col,
+                            direction,
+                        },
+                    ));
+                }
+            }
+            cells.reverse();
+        }
+    }
+
+    result
+}
+
+pub fn count_words(grid: &Grid, words: &[&[u8]]) -> Vec<usize> {
+    let mut counts = vec![0; words.len()];
+    for (word, _) in find_words(grid, words) {
+        counts[word] += 1;
+    }
+    counts
+}
+
+impl Direction {
+    pub const ALL: [Direction; 8] = [
+        Direction::N,
+        Direction::NE,
+        Direction::E,
+        Direction::SE,
+        Direction::S,
+        Direction::SW,
+        Direction::W,
+        Direction::NW,
+    ];
+
+    pub const ORTHOGONAL: [Direction; 4] = [Direction::N, Direction::E, Direction::S, Direction::W];
+
+    pub const DIAGONAL: [Direction; 4] =
+        [Direction::NE, Direction::SE, Direction::SW, Direction::NW];
+
+    pub fn opposite(&self) -> Direction {
+        let (dr, dc) = self.delta();
+        *Direction::ALL
+            .iter()
+            .find(|d| d.delta() == (-dr, -dc))
+            .unwrap()
+    }
+
+    pub fn delta(&self) -> (isize, isize) {
+        match self {
+            Direction::N => (-1, 0),
+            Direction::NE => (-1, 1),
+            Direction::E => (0, 1),
+            Direction::SE => (1, 1),
+            Direction::S => (1, 0),
+            Direction::SW => (1, -1),
+            Direction::W => (0, -1),
+            Direction::NW => (-1, -1),
+        }
+    }
+}
+
+impl Orientation {
+    pub const ROTATIONS: [Orientation; 4] = [
+        Orientation {
+            turns: 0,
+            mirrored: false,
+        },
+        Orientation {
+            turns: 1,
+            mirrored: false,
+        },
+        Orientation {
+            turns: 2,
+            mirrored: false,
+        },
+        Orientation {
+            turns: 3,
+            mirrored: false,
+        },
+    ];
+
+    pub fn all() -> Vec<Orientation> {
+        [false, true]
+            .into_iter()
+            .flat_map(|mirrored| (0..4).map(move |turns| Orientation { turns, mirrored }))
+            .collect()
+    }
+}
+
+impl Pattern {
+    // one line per row, '.' matches any letter
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        Pattern(Grid::from_bytes(bytes).unwrap())
+    }
+
+    fn rotated(&self) -> Self {
+        let (rows, cols) = (self.0.rows, self.0.cols);
+        let mut cells = vec![WILDCARD; self.0.cells.len()];
+        for row in 0..rows {
+            for col in 0..cols {
+                cells[col * rows + (rows - 1 - row)] = self.0[(row, col)];
+            }
+        }
+
+        Pattern(Grid {
+            rows: cols,
+            cols: rows,
+            cells,
+        })
+    }
+
+    fn mirrored(&self) -> Self {
+        let cells = self
+            .0
+            .cells
+            .chunks(self.0.cols)
+            .flat_map(|row| row.iter().rev())
+            .cloned()
+            .collect();
+
+        Pattern(Grid { cells, ..self.0 })
+    }
+
+    pub fn oriented(&self, orientation: Orientation) -> Self {
+        let mut result = if orientation.mirrored {
+            self.mirrored()
+        } else {
+            self.clone()
+        };
+        for _ in 0..orientation.turns % 4 {
+            result = result.rotated();
+        }
+        result
+    }
+
+    fn matches_at(&self, grid: &Grid, row: usize, col: usize) -> bool {
+        (0..self.0.rows).all(|r| {
+            (0..self.0.cols).all(|c| {
+                let expected = self.0[(r, c)];
+                expected == WILDCARD || grid[(row + r, col + c)] == expected
+            })
+        })
+    }
+}
+
+// any rectangle of letters
+pub fn parse(input: &[u8]) -> Result<Grid, ParseError> {
+    Grid::from_bytes(input)
+}
+
+pub fn part1(input: &[u8]) -> usize {
+    find_word(&parse(input).unwrap(), b"XMAS", &Direction::ALL).len()
+}
+
+pub fn part2(input: &[u8]) -> usize {
+    let pattern = Pattern::from_bytes(b"M.S\n.A.\nM.S");
+
+    find_pattern(&parse(input).unwrap(), &pattern, &Orientation::ROTATIONS).len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test::Bencher;
+
+    const TEST_INPUT: &[u8] = include_bytes!("../test_inputs/day04.txt");
+
+    #[test]
+    fn test_part1() {
+        let input = TEST_INPUT.trim_ascii_end();
+        assert_eq!(part1(input), 18);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = TEST_INPUT.trim_ascii_end();
+        assert_eq!(part2(input), 9);
+    }
+
+    #[test]
+    fn test_find_word() {
+        let grid = Grid::from_bytes(b"XMAS\nMMAA\nAXAS\nSSMS").unwrap();
+
+        assert_eq!(
+            find_word(&grid, b"XMAS", &Direction::ALL),
+            vec![
+                WordMatch {
+                    row: 0,
+                    col: 0,
+                    direction: Direction::E
+                },
+                WordMatch {
+                    row: 0,
+                    col: 0,
+                    direction: Direction::SE
+                },
+                WordMatch {
+                    row: 0,
+                    col: 0,
+                    direction: Direction::S
+                },
+            ]
+        );
+        assert_eq!(find_word(&grid, b"XMAS", &Direction::DIAGONAL).len(), 1);
+        assert_eq!(find_word(&grid, b"SAMX", &Direction::ORTHOGONAL).len(), 2);
+        assert_eq!(find_word(&grid, b"AS", &Direction::ALL).len(), 10);
+
+        let grid = Grid::from_bytes(b"AB\nCD").unwrap();
+        assert!(find_word(&grid, b"B.", &[Direction::E]).is_empty());
+        assert_eq!(grid.get((0, 2)), None);
+    }
+
+    #[test]
+    fn test_find_pattern() {
+        let grid = Grid::from_bytes(TEST_INPUT.trim_ascii_end()).unwrap();
+
+        let pattern = Pattern::from_bytes(b"M.S\n.A.\nM.S");
+        assert_eq!(
+            pattern.oriented(Orientation::ROTATIONS[1]),
+            Pattern::from_bytes(b"M.M\n.A.\nS.S")
+        );
+        assert_eq!(find_pattern(&grid, &pattern, &Orientation::all()).len(), 9);
+
+        let grid = Grid::from_bytes(b"..X\nSAM\n...").unwrap();
+        let pattern = Pattern::from_bytes(b"XM\n.A\n.S");
+        assert_eq!(
+            find_pattern(&grid, &pattern, &Orientation::all()),
+            vec![PatternMatch {
+                row: 0,
+                col: 0,
+                orientation: Orientation {
+                    turns: 1,
+                    mirrored: false
+                }
+            }]
+        );
+    }
+
+    #[test]
+    fn test_find_words() {
+        let grid = Grid::from_bytes(TEST_INPUT.trim_ascii_end()).unwrap();
+        let words: [&[u8]; 6] = [b"XMAS", b"MAS", b"AM", b"X", b"SAMX", b"XMASX"];
+
+        let mut found = find_words(&grid, &words);
+        found.sort_by_key(|(word, m)| (*word, m.row, m.col, m.direction as usize));
+
+        let mut expected = vec![];
+        for (ix, word) in words.iter().enumerate() {
+            for m in find_word(&grid, word, &Direction::ALL) {
+                expected.push((ix, m));
+            }
+        }
+
+        assert_eq!(found, expected);
+        assert_eq!(count_words(&grid, &words)[..2], [18, 38]);
+        assert_eq!(count_words(&grid, &[b"SS", b""]), [8, 0]);
+    }
+
+    #[bench]
+    fn bench_part1(b: &mut Bencher) {
+        let input = INPUT.trim_ascii_end();
+        b.iter(|| part1(input))
+    }
+
+    #[bench]
+    fn bench_part2(b: &mut Bencher) {
+        let input = INPUT.trim_ascii_end();
+        b.iter(|| part2(input))
+    }
+}
@@ -0,0 +1,511 @@
+extern crate test;
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::fmt;
+
+use aoc_core::parse::{ParseError, integer};
+
+pub const INPUT: &[u8] = include_bytes!("../inputs/day05.txt");
+
+pub type Page = u32;
+
+// the rules and the updates
+pub type Lists = (Vec<(Page, Page)>, Vec<Vec<Page>>);
+
+// pages in rule order, the first page repeated at the end
+#[derive(Debug, PartialEq, Eq)]
+pub struct Cycle(pub Vec<Page>);
+
+impl fmt::Display for Cycle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let pages = self
+            .0
+            .iter()
+            .map(|p| p.to_string())
+            .collect::<Vec<String>>();
+        write!(f, "ordering rules contain a cycle: {}", pages.join(" -> "))
+    }
+}
+
+fn parse_rule(line: &[u8]) -> Result<(Page, Page), ParseError> {
+    let bar = line
+        .iter()
+        .position(|&b| b == b'|')
+        .ok_or(ParseError::Missing("'|'"))?;
+    Ok((integer(&line[..bar])?, integer(&line[bar + 1..])?))
+}
+
+fn parse_update(line: &[u8]) -> Result<Vec<Page>, ParseError> {
+    let update = line
+        .split(|&b| b == b',')
+        .map(integer::<Page>)
+        .collect::<Result<Vec<Page>, ParseError>>()?;
+
+    // positions and the sort are keyed by page, so a repeated page can't be placed
+    let mut seen = HashSet::new();
+    if !update.iter().all(|page| seen.insert(page)) {
+        return Err(ParseError::Invalid("an update repeats a page"));
+    }
+
+    Ok(update)
+}
+
+// rules, an empty line, then updates, without checking the rules for cycles
+pub fn parse_lists(input: &[u8]) -> Result<Lists, ParseError> {
+    let mut lines = input.split(|&b| b == b'\n');
+
+    let rules = lines
+        .by_ref()
+        .take_while(|line| !line.is_empty())
+        .map(parse_rule)
+        .collect::<Result<_, _>>()?;
+
+    let updates = lines.map(parse_update).collect::<Result<_, _>>()?;
+
+    Ok((rules, updates))
+}
+
+// the rules between the pages of an update, the only ones it has to follow
+fn relevant(rules: &[(Page, Page)], update: &[Page]) -> Vec<(Page, Page)> {
+    let pages = update.iter().collect::<HashSet<&Page>>();
+    rules
+        .iter()
+        .filter(|(a, b)| pages.contains(a) && pages.contains(b))
+        .cloned()
+        .collect()
+}
+
+// like parse_lists, but every update must be sortable
+pub fn parse(input: &[u8]) -> Result<Lists, ParseError> {
+    let (rules, updates) = parse_lists(input)?;
+    if updates
+        .iter()
+        .any(|update| top_sort(update, &relevant(&rules, update)).is_err())
+    {
+        return Err(ParseError::Invalid("ordering rules contain a cycle"));
+    }
+    Ok((rules, updates))
+}
+
+// kahn's algorithm over the given pages and every page mentioned by a rule, ties go to the page
+// that comes first in pages, then in rules
+pub fn top_sort(pages: &[Page], rules: &[(Page, Page)]) -> Result<Vec<Page>, Cycle> {
+    let mut index = HashMap::new();
+    let mut vertices = vec![];
+    for &page in pages.iter().chain(rules.iter().flat_map(|(a, b)| [a, b])) {
+        index.entry(page).or_insert_with(|| {
+            vertices.push(page);
+            vertices.len() - 1
+        });
+    }
+
+    let mut successors = vec![vec![]; vertices.len()];
+    let mut predecessors = vec![vec![]; vertices.len()];
+    let mut in_degree = vec![0; vertices.len()];
+    for (a, b) in rules {
+        let (a, b) = (index[a], index[b]);
+        successors[a].push(b);
+        predecessors[b].push(a);
+        in_degree[b] += 1;
+    }
+
+    let mut ready = (0..vertices.len())
+        .filter(|&v| in_degree[v] == 0)
+        .map(Reverse)
+        .collect::<BinaryHeap<Reverse<usize>>>();
+    let mut sorted = Vec::with_capacity(vertices.len());
+
+    while let Some(Reverse(v)) = ready.pop() {
+        sorted.push(vertices[v]);
+        for &w in &successors[v] {
+            in_degree[w] -= 1;
+            if in_degree[w] == 0 {
+                ready.push(Reverse(w));
+            }
+        }
+    }
+
+    if sorted.len() == vertices.len() {
+        return Ok(sorted);
+    }
+
+    // every unsorted vertex still has an unsorted predecessor, so walking backwards from
+    // any of them must eventually revisit a vertex
+    let mut v = (0..vertices.len()).find(|&v| in_degree[v] > 0).unwrap();
+    let mut seen = HashMap::new();
+    let mut path = vec![];
+    while !seen.contains_key(&v) {
+        seen.insert(v, path.len());
+        path.push(v);
+        v = *predecessors[v].iter().find(|&&u| in_degree[u] > 0).unwrap();
+    }
+
+    let mut cycle = path[seen[&v]..]
+        .iter()
+        .rev()
+        .map(|&v| vertices[v])
+        .collect::<Vec<Page>>();
+    cycle.push(cycle[0]);
+
+    Err(Cycle(cycle))
+}
+
+// rule.0 must come before rule.1 but sits at positions.0, after positions.1
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Violation {
+    pub rule: (Page, Page),
+    pub positions: (usize, usize),
+}
+
+// applied by first taking out every moved page, then inserting them in order of `to`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Move {
+    pub page: Page,
+    pub from: usize,
+    pub to: usize,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Audit {
+    pub update: Vec<Page>,
+    pub violations: Vec<Violation>,
+    pub moves: Vec<Move>,
+    pub fixed: Vec<Page>,
+}
+
+fn augment(
+    left: usize,
+    conflicts: &[Vec<usize>],
+    seen: &mut [bool],
+    matched: &mut [Option<usize>],
+) -> bool {
+    for &right in &conflicts[left] {
+        if !seen[right] {
+            seen[right] = true;
+            if matched[right].is_none_or(|other| augment(other, conflicts, seen, matched)) {
+                matched[right] = Some(left);
+                return true;
+            }
+        }
+    }
+    false
+}
+
+// positions i < j conflict when update[j] has to come before update[i], possibly through other
+// pages. conflicts are transitive, so the largest set of pages that can stay in place is a
+// maximum antichain, read off a maximum matching (dilworth, konig)
+fn largest_consistent(update: &[Page], rules: &[(Page, Page)]) -> Vec<usize> {
+    let n = update.len();
+    let positions = update
+        .iter()
+        .enumerate()
+        .map(|(ix, &page)| (page, ix))
+        .collect::<HashMap<Page, usize>>();
+
+    let mut before = vec![vec![false; n]; n];
+    for (a, b) in rules {
+        before[positions[a]][positions[b]] = true;
+    }
+    for k in 0..n {
+        for i in 0..n {
+            for j in 0..n {
+                before[i][j] |= before[i][k] && before[k][j];
+            }
+        }
+    }
+
+    let conflicts = (0..n)
+        .map(|i| (i + 1..n).filter(|&j| before[j][i]).collect())
+        .collect::<Vec<Vec<usize>>>();
+    let mut matched = vec![None; n];
+    for left in 0..n {
+        augment(left, &conflicts, &mut vec![false; n], &mut matched);
+    }
+
+    // alternating search from the unmatched left vertices
+    let mut reached_left = vec![false; n];
+    let mut reached_right = vec![false; n];
+    let mut stack = (0..n)
+        .filter(|&left| !matched.contains(&Some(left)))
+        .collect::<Vec<usize>>();
+    while let Some(left) = stack.pop() {
+        if reached_left[left] {
+            continue;
+        }
+        reached_left[left] = true;
+        for &right in &conflicts[left] {
+            if !reached_right[right] {
+                reached_right[right] = true;
+                stack.extend(matched[right]);
+            }
+        }
+    }
+
+    (0..n)
+        .filter(|&v| reached_left[v] && !reached_right[v])
+        .collect()
+}
+
+// the fewest moves is the number of pages outside a largest set that breaks no rule, the kept
+// pages are chained in their current order so sorting leaves them where they are
+pub fn audit(rules: &[(Page, Page)], update: &[Page]) -> Result<Audit, Cycle> {
+    let positions = update
+        .iter()
+        .enumerate()
+        .map(|(ix, &page)| (page, ix))
+        .collect::<HashMap<Page, usize>>();
+
+    let subgraph = rules
+        .iter()
+        .filter(|(a, b)| positions.contains_key(a) && positions.contains_key(b))
+        .cloned()
+        .collect::<Vec<(Page, Page)>>();
+
+    let mut violations = subgraph
+        .iter()
+        .map(|&(a, b)| Violation {
+            rule: (a, b),
+            positions: (positions[&a], positions[&b]),
+        })
+        .filter(|v| v.positions.0 > v.positions.1)
+        .collect::<Vec<Violation>>();
+    violations.sort_by_key(|v| (v.positions.1, v.positions.0));
+
+    top_sort(update, &subgraph)?;
+    let kept = largest_consistent(update, &subgraph);
+    let chained = subgraph
+        .iter()
+        .cloned()
+        .chain(kept.windows(2).map(|w| (update[w[0]], update[w[1]])))
+        .collect::<Vec<(Page, Page)>>();
+
+    let fixed = top_sort(update, &chained)?;
+    let ranks = fixed
+        .iter()
+        .enumerate()
+        .map(|(ix, &page)| (page, ix))
+        .collect::<HashMap<Page, usize>>();
+
+    let kept = kept.into_iter().collect::<HashSet<usize>>();
+    let mut moves = (0..update.len())
+        .filter(|ix| !kept.contains(ix))
+        .map(|ix| Move {
+            page: update[ix],
+            from: ix,
+            to: ranks[&update[ix]],
+        })
+        .collect::<Vec<Move>>();
+    moves.sort_by_key(|m| m.to);
+
+    Ok(Audit {
+        update: update.to_vec(),
+        violations,
+        moves,
+        fixed,
+    })
+}
+
+fn join(pages: &[Page]) -> String {
+    pages
+        .iter()
+        .map(|p| p.to_string())
+        .collect::<Vec<String>>()
+        .join(",")
+}
+
+pub fn audit_report(input: &[u8]) -> String {
+    let (rules, updates) = parse_lists(input).unwrap();
+    let mut lines = vec![];
+
+    for (ix, update) in updates.iter().enumerate() {
+        let audit = match audit(&rules, update) {
+            Ok(audit) if audit.violations.is_empty() => continue,
+            Ok(audit) => audit,
+            Err(cycle) => {
+                lines.push(format!("update {}: {}", ix + 1, cycle));
+                continue;
+            }
+        };
+
+        lines.push(format!("update {}: {}", ix + 1, join(&audit.update)));
+        for v in &audit.violations {
+            lines.push(format!(
+                "  violates {}|{}: {} at {}, {} at {}",
+                v.rule.0, v.rule.1, v.rule.0, v.positions.0, v.rule.1, v.positions.1
+            ));
+        }
+        for m in &audit.moves {
+            lines.push(format!("  move {} from {} to {}", m.page, m.from, m.to));
+        }
+        lines.push(format!("  fixed: {}", join(&audit.fixed)));
+    }
+
+    lines.join("\n")
+}
+
+pub fn part1(input: &[u8]) -> usize {
+    let (rules, updates) = parse(input).unwrap();
+    let rules = rules.into_iter().collect::<HashSet<(Page, Page)>>();
+
+    let mut result = 0;
+
+    'outer: for sequence in updates {
+        for i in 0..sequence.len() {
+            for j in i + 1..sequence.len() {
+                if rules.contains(&(sequence[j], sequence[i])) {
+                    continue 'outer;
+                }
+            }
+        }
+        result += sequence[sequence.len() / 2] as usize;
+    }
+
+    result
+}
+
+pub fn part2(input: &[u8]) -> usize {
+    let (rules, updates) = parse(input).unwrap();
+
+    let mut result = 0;
+
+    for sequence in updates {
+        let sorted = top_sort(&sequence, &relevant(&rules, &sequence))
+            .unwrap_or_else(|cycle| panic!("{}", cycle));
+        if sorted != sequence {
+            result += sorted[sorted.len() / 2] as usize;
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test::Bencher;
+
+    const TEST_INPUT: &[u8] = include_bytes!("../test_inputs/day05.txt");
+
+    #[test]
+    fn test_part1() {
+        let input = TEST_INPUT.trim_ascii_end();
+        assert_eq!(part1(input), 143);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = TEST_INPUT.trim_ascii_end();
+        assert_eq!(part2(input), 123);
+    }
+
+    #[test]
+    fn test_parse() {
+        let cyclic = b"1|2\n2|1\n\n1,2";
+        assert!(parse_lists(cyclic).is_ok());
+        assert_eq!(
+            parse(cyclic),
+            Err(ParseError::Invalid("ordering rules contain a cycle"))
+        );
+        assert_eq!(
+            parse(b"1|2\n2|1\n\n3,4"),
+            Ok((vec![(1, 2), (2, 1)], vec![vec![3, 4]]))
+        );
+        assert_eq!(parse(b"12\n\n1"), Err(ParseError::Missing("'|'")));
+        assert_eq!(parse(b"1|2\n\n1,,2"), Err(ParseError::Empty));
+        assert_eq!(
+            parse_lists(b"1|2\n\n1,2,1"),
+            Err(ParseError::Invalid("an update repeats a page"))
+        );
+    }
+
+    #[test]
+    fn test_top_sort() {
+        assert_eq!(
+            top_sort(&[7], &[(100, 250), (250, 999), (100, 999)]),
+            Ok(vec![7, 100, 250, 999])
+        );
+
+        let cycle = top_sort(&[], &[(1, 2), (2, 3), (3, 4), (4, 2), (4, 5)]).unwrap_err();
+        assert_eq!(cycle, Cycle(vec![3, 4, 2, 3]));
+        assert_eq!(
+            cycle.to_string(),
+            "ordering rules contain a cycle: 3 -> 4 -> 2 -> 3"
+        );
+    }
+
+    #[test]
+    fn test_audit() {
+        let (rules, updates) = parse(TEST_INPUT.trim_ascii_end()).unwrap();
+
+        let report = audit(&rules, &updates[0]).unwrap();
+        assert!(report.violations.is_empty() && report.moves.is_empty());
+
+        let report = audit(&rules, &updates[3]).unwrap();
+        assert_eq!(
+            report.violations,
+            vec![Violation {
+                rule: (97, 75),
+                positions: (1, 0)
+            }]
+        );
+        assert_eq!(report.moves.len(), 1);
+        assert_eq!(report.fixed, vec![97, 75, 47, 61, 53]);
+
+        let report = audit(&rules, &updates[5]).unwrap();
+        assert_eq!(report.violations.len(), 4);
+        assert_eq!(
+            report.moves,
+            vec![
+                Move {
+                    page: 29,
+                    from: 3,
+                    to: 3
+                },
+                Move {
+                    page: 13,
+                    from: 1,
+                    to: 4
+                },
+            ]
+        );
+        assert_eq!(report.fixed, vec![97, 75, 47, 29, 13]);
+    }
+
+    #[test]
+    fn test_audit_partial_order() {
+        let report = audit(&[(1, 2), (1, 3)], &[2, 3, 9, 1]).unwrap();
+        assert_eq!(
+            report.moves,
+            vec![Move {
+                page: 1,
+                from: 3,
+                to: 0
+            }]
+        );
+        assert_eq!(report.fixed, vec![1, 2, 3, 9]);
+
+        // 4 has to come before 2 only through 3
+        let report = audit(&[(4, 3), (3, 2)], &[2, 5, 3, 4]).unwrap();
+        assert_eq!(report.violations.len(), 2);
+        assert_eq!(report.moves.len(), 2);
+        assert_eq!(report.fixed, vec![5, 4, 3, 2]);
+
+        assert_eq!(
+            top_sort(&[2, 3, 9, 1], &[(1, 2), (1, 3)]),
+            Ok(vec![9, 1, 2, 3])
+        );
+    }
+
+    #[bench]
+    fn bench_part1(b: &mut Bencher) {
+        let input = INPUT.trim_ascii_end();
+        b.iter(|| part1(input))
+    }
+
+    #[bench]
+    fn bench_part2(b: &mut Bencher) {
+        let input = INPUT.trim_ascii_end();
+        b.iter(|| part2(input))
+    }
+}
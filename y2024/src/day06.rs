@@ -0,0 +1,588 @@
+extern crate test;
+
+use std::collections::HashSet;
+
+use aoc_core::cancel::{Cancel, Cancelled};
+use aoc_core::grid::Grid;
+use aoc_core::parse::ParseError;
+use rayon::prelude::*;
+
+pub const INPUT: &[u8] = include_bytes!("../inputs/day06.txt");
+
+const EXIT: usize = usize::MAX;
+
+// orthogonal directions come first so they can index the jump tables
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+    UpRight,
+    DownRight,
+    DownLeft,
+    UpLeft,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Turn {
+    Right,
+    Left,
+    Reverse,
+}
+
+#[derive(Debug, Clone)]
+pub struct Rules {
+    pub turn: Turn,
+    // turns are by 45 degrees, so the guard also walks diagonally
+    pub diagonal: bool,
+    // every cell holding one of these is a guard starting out in that direction
+    pub markers: Vec<(u8, Direction)>,
+}
+
+// guards don't see each other, each one patrols the map as if it were alone
+#[derive(Debug, PartialEq, Eq)]
+pub struct Route {
+    pub start: (usize, usize),
+    pub direction: Direction,
+    pub visited: usize,
+    pub looped: bool,
+}
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Guard {
+    position: (isize, isize),
+    direction: Direction,
+}
+
+// states are (row, col) and the direction the guard faces there, turning in place is a
+// separate state, so length is the number of steps before the guard repeats itself
+#[derive(Debug)]
+pub struct Loop {
+    pub obstruction: (usize, usize),
+    pub states: Vec<((usize, usize), Direction)>,
+    pub length: usize,
+}
+
+// for every cell and direction, the cell the guard stops on before the next wall or EXIT
+// if it walks off the map
+struct Jumps {
+    cols: usize,
+    next: Vec<[usize; 4]>,
+}
+
+// true for walls
+fn walls(input: &[u8]) -> Grid<bool> {
+    Grid::from_bytes(input).unwrap().map(|&c| c == b'#')
+}
+
+impl Direction {
+    const ORTHOGONAL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    const CLOCKWISE: [Direction; 8] = [
+        Direction::Up,
+        Direction::UpRight,
+        Direction::Right,
+        Direction::DownRight,
+        Direction::Down,
+        Direction::DownLeft,
+        Direction::Left,
+        Direction::UpLeft,
+    ];
+
+    fn from_byte(byte: u8) -> Option<Self> {
+        match byte {
+            b'^' => Some(Self::Up),
+            b'v' => Some(Self::Down),
+            b'<' => Some(Self::Left),
+            b'>' => Some(Self::Right),
+            _ => None,
+        }
+    }
+
+    // index into CLOCKWISE
+    fn clockwise_index(&self) -> usize {
+        match self {
+            Direction::Up => 0,
+            Direction::UpRight => 1,
+            Direction::Right => 2,
+            Direction::DownRight => 3,
+            Direction::Down => 4,
+            Direction::DownLeft => 5,
+            Direction::Left => 6,
+            Direction::UpLeft => 7,
+        }
+    }
+
+    fn rotate(&self, eighths: isize) -> Self {
+        Direction::CLOCKWISE[(self.clockwise_index() as isize + eighths).rem_euclid(8) as usize]
+    }
+
+    // a quarter turn clockwise, spelled out since find_loop calls it on every turn
+    fn next(&self) -> Self {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+            Direction::UpRight => Direction::DownRight,
+            Direction::DownRight => Direction::DownLeft,
+            Direction::DownLeft => Direction::UpLeft,
+            Direction::UpLeft => Direction::UpRight,
+        }
+    }
+
+    fn delta(&self) -> (isize, isize) {
+        match self {
+            Direction::Up => (-1, 0),
+            Direction::Right => (0, 1),
+            Direction::Down => (1, 0),
+            Direction::Left => (0, -1),
+            Direction::UpRight => (-1, 1),
+            Direction::DownRight => (1, 1),
+            Direction::DownLeft => (1, -1),
+            Direction::UpLeft => (-1, -1),
+        }
+    }
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Self {
+            turn: Turn::Right,
+            diagonal: false,
+            markers: b"^>v<"
+                .iter()
+                .map(|&b| (b, Direction::from_byte(b).unwrap()))
+                .collect(),
+        }
+    }
+}
+
+impl Guard {
+    fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        Guard::all_from_bytes(bytes, &Rules::default().markers)
+            .into_iter()
+            .next()
+    }
+
+    fn all_from_bytes(bytes: &[u8], markers: &[(u8, Direction)]) -> Vec<Self> {
+        let mut guards = vec![];
+        for (i, line) in bytes.split(|&b| b == b'\n').enumerate() {
+            for (j, &c) in line.iter().enumerate() {
+                if let Some(&(_, direction)) = markers.iter().find(|(m, _)| *m == c) {
+                    guards.push(Self {
+                        position: (i as isize, j as isize),
+                        direction,
+                    });
+                }
+            }
+        }
+        guards
+    }
+
+    fn next_pos(&self) -> (isize, isize) {
+        let (dr, dc) = self.direction.delta();
+        (self.position.0 + dr, self.position.1 + dc)
+    }
+
+    fn turn(&mut self, rules: &Rules) {
+        let eighths = if rules.diagonal { 1 } else { 2 };
+        self.direction = match rules.turn {
+            Turn::Right => self.direction.rotate(eighths),
+            Turn::Left => self.direction.rotate(-eighths),
+            Turn::Reverse => self.direction.rotate(4),
+        };
+    }
+}
+
+impl Jumps {
+    fn new(grid: &Grid<bool>) -> Self {
+        let mut next = vec![[EXIT; 4]; grid.cells.len()];
+
+        for direction in Direction::ORTHOGONAL {
+            let (dr, dc) = direction.delta();
+            let d = direction as usize;
+
+            // visit cells nearest to the edge the guard is walking towards first
+            let mut order = (0..grid.cells.len()).collect::<Vec<usize>>();
+            if dr > 0 || dc > 0 {
+                order.reverse();
+            }
+
+            for cell in order {
+                let pos = ((cell / grid.cols) as isize, (cell % grid.cols) as isize);
+                let ahead = (pos.0 + dr, pos.1 + dc);
+                next[cell][d] = if !grid.contains(ahead) {
+                    EXIT
+                } else if grid[(ahead.0 as usize, ahead.1 as usize)] {
+                    cell
+                } else {
+                    next[ahead.0 as usize * grid.cols + ahead.1 as usize][d]
+                };
+            }
+        }
+
+        Self {
+            cols: grid.cols,
+            next,
+        }
+    }
+
+    // same as next but with one extra wall at obstruction
+    fn target(&self, cell: usize, direction: Direction, obstruction: usize) -> usize {
+        let target = self.next[cell][direction as usize];
+        let (row, col) = (cell / self.cols, cell % self.cols);
+        let (o_row, o_col) = (obstruction / self.cols, obstruction % self.cols);
+        let (t_row, t_col) = (target / self.cols, target % self.cols);
+
+        let blocked = match direction {
+            Direction::Up => o_col == col && o_row < row && (target == EXIT || o_row >= t_row),
+            Direction::Down => o_col == col && o_row > row && (target == EXIT || o_row <= t_row),
+            Direction::Left => o_row == row && o_col < col && (target == EXIT || o_col >= t_col),
+            Direction::Right => o_row == row && o_col > col && (target == EXIT || o_col <= t_col),
+            _ => unreachable!("jump tables only cover orthogonal directions"),
+        };
+
+        if !blocked {
+            return target;
+        }
+
+        let (dr, dc) = direction.delta();
+        (o_row as isize - dr) as usize * self.cols + (o_col as isize - dc) as usize
+    }
+}
+
+// the states where the guard turns are enough to detect a loop, and each one is a single
+// table lookup away from the previous, returns the turns making up the loop
+fn find_loop(
+    jumps: &Jumps,
+    start: usize,
+    direction: Direction,
+    obstruction: usize,
+    cancel: &Cancel,
+) -> Result<Option<Vec<(usize, Direction)>>, Cancelled> {
+    let mut visited = vec![0u64; (jumps.next.len() * 4).div_ceil(64)];
+    let mut turns = vec![];
+    let (mut cell, mut direction) = (start, direction);
+
+    loop {
+        cancel.check()?;
+
+        cell = jumps.target(cell, direction, obstruction);
+        if cell == EXIT {
+            return Ok(None);
+        }
+
+        let state = cell * 4 + direction as usize;
+        if visited[state / 64] & (1 << (state % 64)) != 0 {
+            let first = turns.iter().position(|&t| t == (cell, direction)).unwrap();
+            return Ok(Some(turns.split_off(first)));
+        }
+        visited[state / 64] |= 1 << (state % 64);
+        turns.push((cell, direction));
+
+        direction = direction.next();
+    }
+}
+
+// fills in the cells walked between consecutive turns
+fn expand(cols: usize, turns: &[(usize, Direction)]) -> Vec<((usize, usize), Direction)> {
+    let mut states = vec![];
+
+    for (ix, &(cell, direction)) in turns.iter().enumerate() {
+        let end = turns[(ix + 1) % turns.len()].0;
+        states.push(((cell / cols, cell % cols), direction));
+
+        let direction = direction.next();
+        let (dr, dc) = direction.delta();
+
+        let mut pos = ((cell / cols) as isize, (cell % cols) as isize);
+        while pos.0 as usize * cols + pos.1 as usize != end {
+            states.push(((pos.0 as usize, pos.1 as usize), direction));
+            pos = (pos.0 + dr, pos.1 + dc);
+        }
+    }
+
+    states
+}
+
+// distinct cells in the order they are first visited, and whether the guard never leaves;
+// there are only 8 states per cell, so walking for longer than that means a loop
+fn patrol(grid: &Grid<bool>, mut guard: Guard, rules: &Rules) -> (Vec<(isize, isize)>, bool) {
+    let mut visited: HashSet<(isize, isize)> = HashSet::new();
+    let mut path = vec![guard.position];
+    visited.insert(guard.position);
+
+    for _ in 0..=grid.cells.len() * 8 {
+        let next_pos = guard.next_pos();
+        if !grid.contains(next_pos) {
+            return (path, false);
+        }
+        if grid[(next_pos.0 as usize, next_pos.1 as usize)] {
+            guard.turn(rules);
+            continue;
+        }
+        guard.position = next_pos;
+        if visited.insert(next_pos) {
+            path.push(next_pos);
+        }
+    }
+
+    (path, true)
+}
+
+pub fn simulate(input: &[u8], rules: &Rules) -> Vec<Route> {
+    let grid = walls(input);
+
+    Guard::all_from_bytes(input, &rules.markers)
+        .into_iter()
+        .map(|guard| {
+            let start = (guard.position.0 as usize, guard.position.1 as usize);
+            let direction = guard.direction;
+            let (path, looped) = patrol(&grid, guard, rules);
+
+            Route {
+                start,
+                direction,
+                visited: path.len(),
+                looped,
+            }
+        })
+        .collect()
+}
+
+// walls and the guard, nothing else can be on the map
+pub fn parse(input: &[u8]) -> Result<(Grid<bool>, Guard), ParseError> {
+    let grid = Grid::parse(input, |b| match b {
+        b'.' | b'#' | b'^' | b'>' | b'v' | b'<' => Ok(b == b'#'),
+        _ => Err(ParseError::InvalidByte(b)),
+    })?;
+    let guard = Guard::from_bytes(input).ok_or(ParseError::Missing("guard"))?;
+    Ok((grid, guard))
+}
+
+pub fn part1(input: &[u8]) -> usize {
+    let (grid, guard) = parse(input).unwrap();
+
+    patrol(&grid, guard, &Rules::default()).0.len()
+}
+
+type Trap = ((usize, usize), Vec<(usize, Direction)>);
+
+// every obstruction that traps the guard with the turns of its loop, plus the number of columns
+// to decode them; only cells on the original path can change where the guard goes
+fn traps(input: &[u8], cancel: &Cancel) -> Result<(usize, Vec<Trap>), Cancelled> {
+    let (grid, guard) = parse(input).unwrap();
+    let jumps = Jumps::new(&grid);
+
+    let start = guard.position.0 as usize * grid.cols + guard.position.1 as usize;
+
+    let traps = patrol(&grid, guard.clone(), &Rules::default())
+        .0
+        .into_par_iter()
+        .skip(1)
+        .map(|(row, col)| {
+            let obstruction = row as usize * grid.cols + col as usize;
+            let turns = find_loop(&jumps, start, guard.direction, obstruction, cancel)?;
+            Ok(turns.map(|turns| ((row as usize, col as usize), turns)))
+        })
+        .collect::<Result<Vec<Option<Trap>>, Cancelled>>()?;
+
+    Ok((grid.cols, traps.into_iter().flatten().collect()))
+}
+
+fn obstructions(input: &[u8], cancel: &Cancel) -> Result<Vec<Loop>, Cancelled> {
+    let (cols, traps) = traps(input, cancel)?;
+
+    Ok(traps
+        .into_iter()
+        .map(|(obstruction, turns)| {
+            let states = expand(cols, &turns);
+            Loop {
+                obstruction,
+                length: states.len(),
+                states,
+            }
+        })
+        .collect())
+}
+
+pub fn loops(input: &[u8]) -> Vec<Loop> {
+    obstructions(input, &Cancel::default()).unwrap()
+}
+
+// the map with the loop drawn in, | and - for the direction walked, + where both happen
+// and O for the obstruction
+pub fn render(input: &[u8], selected: &Loop) -> String {
+    let mut lines = input
+        .split(|&b| b == b'\n')
+        .map(|line| line.to_vec())
+        .collect::<Vec<Vec<u8>>>();
+
+    for &((row, col), direction) in &selected.states {
+        let cell = &mut lines[row][col];
+        let mark = match direction {
+            Direction::Up | Direction::Down => b'|',
+            Direction::Left | Direction::Right => b'-',
+            Direction::UpLeft | Direction::DownRight => b'\\',
+            Direction::UpRight | Direction::DownLeft => b'/',
+        };
+        *cell = match *cell {
+            b'.' => mark,
+            b'|' | b'-' | b'\\' | b'/' if *cell != mark => b'+',
+            c => c,
+        };
+    }
+    lines[selected.obstruction.0][selected.obstruction.1] = b'O';
+
+    lines
+        .into_iter()
+        .map(|line| String::from_utf8(line).unwrap())
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+pub fn part2_with_cancel(input: &[u8], cancel: &Cancel) -> Result<usize, Cancelled> {
+    traps(input, cancel).map(|(_, traps)| traps.len())
+}
+
+pub fn part2(input: &[u8]) -> usize {
+    part2_with_cancel(input, &Cancel::default()).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test::Bencher;
+
+    const TEST_INPUT: &[u8] = include_bytes!("../test_inputs/day06.txt");
+
+    #[test]
+    fn test_part1() {
+        let input = TEST_INPUT.trim_ascii_end();
+        assert_eq!(part1(input), 41);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = TEST_INPUT.trim_ascii_end();
+        assert_eq!(part2(input), 6);
+    }
+
+    #[test]
+    fn test_parse() {
+        let (grid, guard) = parse(b"#^\n..").unwrap();
+        assert_eq!(grid.cells, [true, false, false, false]);
+        assert_eq!((guard.position, guard.direction), ((0, 1), Direction::Up));
+        assert!(matches!(
+            parse(b"..\n.#"),
+            Err(ParseError::Missing("guard"))
+        ));
+        assert!(matches!(
+            parse(b".^\nO."),
+            Err(ParseError::InvalidByte(b'O'))
+        ));
+    }
+
+    #[test]
+    fn test_loops() {
+        let input = TEST_INPUT.trim_ascii_end();
+        let loops = loops(input);
+
+        let mut obstructions = loops.iter().map(|l| l.obstruction).collect::<Vec<_>>();
+        obstructions.sort();
+        assert_eq!(
+            obstructions,
+            vec![(6, 3), (7, 6), (7, 7), (8, 1), (8, 3), (9, 7)]
+        );
+
+        let selected = loops.iter().find(|l| l.obstruction == (6, 3)).unwrap();
+        assert_eq!(selected.length, 22);
+        assert_eq!(
+            render(input, selected),
+            [
+                "....#.....",
+                "....+---+#",
+                "....|...|.",
+                "..#.|...|.",
+                "....|..#|.",
+                "....|...|.",
+                ".#.O^---+.",
+                "........#.",
+                "#.........",
+                "......#...",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn test_rules() {
+        let input = TEST_INPUT.trim_ascii_end();
+
+        let routes = simulate(input, &Rules::default());
+        assert_eq!(
+            routes,
+            vec![Route {
+                start: (6, 4),
+                direction: Direction::Up,
+                visited: 41,
+                looped: false
+            }]
+        );
+
+        let rules = Rules {
+            turn: Turn::Reverse,
+            ..Rules::default()
+        };
+        assert_eq!(simulate(input, &rules)[0].visited, 9);
+
+        let rules = Rules {
+            turn: Turn::Left,
+            ..Rules::default()
+        };
+        assert_eq!(simulate(input, &rules)[0].visited, 10);
+
+        let input = b"..#.#\n....#\n..^..\n.....\n#A...";
+        let mut rules = Rules {
+            turn: Turn::Right,
+            diagonal: true,
+            ..Rules::default()
+        };
+        rules.markers.push((b'A', Direction::UpRight));
+        let routes = simulate(input, &rules);
+
+        assert_eq!(routes.len(), 2);
+        assert_eq!((routes[0].start, routes[0].visited), ((2, 2), 3));
+        assert_eq!(
+            (routes[1].start, routes[1].direction, routes[1].visited),
+            ((4, 1), Direction::UpRight, 4)
+        );
+    }
+
+    #[test]
+    fn test_rotate() {
+        for direction in Direction::CLOCKWISE {
+            assert_eq!(direction.next(), direction.rotate(2));
+            assert_eq!(direction.rotate(-1).rotate(9), direction);
+        }
+    }
+
+    #[bench]
+    fn bench_part1(b: &mut Bencher) {
+        let input = INPUT.trim_ascii_end();
+        b.iter(|| part1(input))
+    }
+
+    #[bench]
+    fn bench_part2(b: &mut Bencher) {
+        let input = INPUT.trim_ascii_end();
+        b.iter(|| part2(input))
+    }
+}
@@ -0,0 +1,522 @@
+extern crate test;
+
+use std::ops::ControlFlow;
+
+use aoc_core::parse::{ParseError, integer};
+
+pub const INPUT: &str = include_str!("../inputs/day07.txt");
+
+pub type Value = u128;
+
+fn parse_line(line: &str) -> Result<(Value, Vec<Value>), ParseError> {
+    let (left, right) = line.split_once(": ").ok_or(ParseError::Missing("': '"))?;
+    let left_value = integer::<Value>(left.as_bytes())?;
+    let right_values = right
+        .split(' ')
+        .map(|group| integer::<Value>(group.as_bytes()))
+        .collect::<Result<Vec<Value>, ParseError>>()?;
+
+    Ok((left_value, right_values))
+}
+
+// one equation per line, the target and the values to combine
+pub fn parse(input: &str) -> Result<Vec<(Value, Vec<Value>)>, ParseError> {
+    input.lines().map(parse_line).collect()
+}
+
+// equations are evaluated left to right, so the search runs backwards from the target and
+// needs the left operand that turns right into result
+pub trait Operator: Sync {
+    fn symbol(&self) -> String;
+
+    fn apply(&self, left: Value, right: Value) -> Option<Value>;
+
+    fn unapply(&self, right: Value, result: Value) -> Option<Value>;
+
+    // whether right turns every left operand into result, which unapply can't undo, so the
+    // search has to try the values before it going forwards
+    fn lossy(&self, _right: Value, _result: Value) -> bool {
+        false
+    }
+}
+
+pub struct Add;
+pub struct Mul;
+pub struct Sub;
+// exact division only, otherwise the inverse isn't unique
+pub struct Div;
+pub struct Pow;
+pub struct Cat {
+    pub base: Value,
+}
+
+pub const CAT: Cat = Cat { base: 10 };
+
+pub const PART1: &[&dyn Operator] = &[&Add, &Mul];
+pub const PART2: &[&dyn Operator] = &[&Add, &Mul, &CAT];
+
+impl Operator for Add {
+    fn symbol(&self) -> String {
+        "+".to_string()
+    }
+
+    fn apply(&self, left: Value, right: Value) -> Option<Value> {
+        left.checked_add(right)
+    }
+
+    fn unapply(&self, right: Value, result: Value) -> Option<Value> {
+        result.checked_sub(right)
+    }
+}
+
+impl Operator for Mul {
+    fn symbol(&self) -> String {
+        "*".to_string()
+    }
+
+    fn apply(&self, left: Value, right: Value) -> Option<Value> {
+        left.checked_mul(right)
+    }
+
+    // multiplying by zero loses the left operand, so it can't be undone
+    fn unapply(&self, right: Value, result: Value) -> Option<Value> {
+        if right != 0 && result.is_multiple_of(right) {
+            Some(result / right)
+        } else {
+            None
+        }
+    }
+
+    fn lossy(&self, right: Value, result: Value) -> bool {
+        right == 0 && result == 0
+    }
+}
+
+impl Operator for Sub {
+    fn symbol(&self) -> String {
+        "-".to_string()
+    }
+
+    fn apply(&self, left: Value, right: Value) -> Option<Value> {
+        left.checked_sub(right)
+    }
+
+    fn unapply(&self, right: Value, result: Value) -> Option<Value> {
+        result.checked_add(right)
+    }
+}
+
+impl Operator for Div {
+    fn symbol(&self) -> String {
+        "/".to_string()
+    }
+
+    fn apply(&self, left: Value, right: Value) -> Option<Value> {
+        if right != 0 && left.is_multiple_of(right) {
+            Some(left / right)
+        } else {
+            None
+        }
+    }
+
+    fn unapply(&self, right: Value, result: Value) -> Option<Value> {
+        if right == 0 {
+            None
+        } else {
+            result.checked_mul(right)
+        }
+    }
+}
+
+impl Operator for Pow {
+    fn symbol(&self) -> String {
+        "^".to_string()
+    }
+
+    fn apply(&self, left: Value, right: Value) -> Option<Value> {
+        left.checked_pow(u32::try_from(right).ok()?)
+    }
+
+    // integer root by bisection, x^0 is 1 for every x so it can't be undone
+    fn unapply(&self, right: Value, result: Value) -> Option<Value> {
+        if right == 0 {
+            return None;
+        }
+
+        let (mut lo, mut hi) = (0, result);
+        while lo < hi {
+            let mid = lo + (hi - lo).div_ceil(2);
+            match self.apply(mid, right) {
+                Some(value) if value <= result => lo = mid,
+                _ => hi = mid - 1,
+            }
+        }
+
+        (self.apply(lo, right) == Some(result)).then_some(lo)
+    }
+
+    fn lossy(&self, right: Value, result: Value) -> bool {
+        right == 0 && result == 1
+    }
+}
+
+impl Cat {
+    // smallest power of base above value, the factor left is shifted by
+    fn shift(&self, value: Value) -> Option<Value> {
+        let mut shift: Value = self.base;
+        while shift <= value {
+            shift = shift.checked_mul(self.base)?;
+        }
+        Some(shift)
+    }
+}
+
+impl Operator for Cat {
+    fn symbol(&self) -> String {
+        if self.base == 10 {
+            "||".to_string()
+        } else {
+            format!("||{}", self.base)
+        }
+    }
+
+    fn apply(&self, left: Value, right: Value) -> Option<Value> {
+        left.checked_mul(self.shift(right)?)?.checked_add(right)
+    }
+
+    // a left operand of zero leaves right as it is
+    fn unapply(&self, right: Value, result: Value) -> Option<Value> {
+        let shift = self.shift(right)?;
+        if result % shift == right {
+            Some(result / shift)
+        } else {
+            None
+        }
+    }
+}
+
+// names as accepted on the command line, catN concatenates in base N
+pub fn operator(name: &str) -> Option<Box<dyn Operator>> {
+    match name {
+        "add" => Some(Box::new(Add)),
+        "mul" => Some(Box::new(Mul)),
+        "sub" => Some(Box::new(Sub)),
+        "div" => Some(Box::new(Div)),
+        "pow" => Some(Box::new(Pow)),
+        "cat" => Some(Box::new(CAT)),
+        _ => {
+            let base = name.strip_prefix("cat")?.parse().ok()?;
+            (base >= 2).then(|| Box::new(Cat { base }) as Box<dyn Operator>)
+        }
+    }
+}
+
+struct State<'a> {
+    target: Value,
+    values: &'a [Value],
+}
+
+impl State<'_> {
+    fn is_final(&self) -> bool {
+        self.values.len() == 1 && self.values[0] == self.target
+    }
+
+    fn next(&self, op: &dyn Operator) -> Option<Self> {
+        self.values.split_last().and_then(|(&val, rest)| {
+            op.unapply(val, self.target).map(|new_target| Self {
+                target: new_target,
+                values: rest,
+            })
+        })
+    }
+}
+
+// every way to combine acc with values, visit gets the operations in reading order and the result
+fn forward<'a>(
+    acc: Value,
+    values: &[Value],
+    operations: &[&'a dyn Operator],
+    path: &mut Vec<&'a dyn Operator>,
+    visit: &mut impl FnMut(&[&'a dyn Operator], Value) -> ControlFlow<()>,
+) -> ControlFlow<()> {
+    let Some((&value, rest)) = values.split_first() else {
+        return visit(path, acc);
+    };
+
+    for &op in operations {
+        if let Some(acc) = op.apply(acc, value) {
+            path.push(op);
+            forward(acc, rest, operations, path, visit)?;
+            path.pop();
+        }
+    }
+
+    ControlFlow::Continue(())
+}
+
+// operations are found from the last value backwards, visit gets them in reading order
+fn dfs<'a>(
+    state: &State,
+    operations: &[&'a dyn Operator],
+    path: &mut Vec<&'a dyn Operator>,
+    visit: &mut impl FnMut(Vec<&'a dyn Operator>) -> ControlFlow<()>,
+) -> ControlFlow<()> {
+    if state.is_final() {
+        return visit(path.iter().rev().cloned().collect());
+    }
+
+    let Some((&value, rest)) = state.values.split_last() else {
+        return ControlFlow::Continue(());
+    };
+
+    for &op in operations {
+        if let Some(next) = state.next(op) {
+            path.push(op);
+            dfs(&next, operations, path, visit)?;
+            path.pop();
+        } else if op.lossy(value, state.target)
+            && let Some((&first, rest)) = rest.split_first()
+        {
+            forward(first, rest, operations, &mut vec![], &mut |before, left| {
+                if op.apply(left, value) == Some(state.target) {
+                    visit(
+                        before
+                            .iter()
+                            .chain([&op])
+                            .chain(path.iter().rev())
+                            .cloned()
+                            .collect(),
+                    )
+                } else {
+                    ControlFlow::Continue(())
+                }
+            })?;
+        }
+    }
+
+    ControlFlow::Continue(())
+}
+
+pub fn solve<'a>(
+    target: Value,
+    values: &[Value],
+    operations: &[&'a dyn Operator],
+) -> Option<Vec<&'a dyn Operator>> {
+    let mut result = None;
+    let _ = dfs(
+        &State { target, values },
+        operations,
+        &mut vec![],
+        &mut |solution| {
+            result = Some(solution);
+            ControlFlow::Break(())
+        },
+    );
+    result
+}
+
+pub fn solutions<'a>(
+    target: Value,
+    values: &[Value],
+    operations: &[&'a dyn Operator],
+) -> Vec<Vec<&'a dyn Operator>> {
+    let mut result = vec![];
+    let _ = dfs(
+        &State { target, values },
+        operations,
+        &mut vec![],
+        &mut |solution| {
+            result.push(solution);
+            ControlFlow::Continue(())
+        },
+    );
+    result
+}
+
+pub fn evaluate(values: &[Value], operations: &[&dyn Operator]) -> Option<Value> {
+    let (&first, rest) = values.split_first()?;
+    rest.iter()
+        .zip(operations)
+        .try_fold(first, |acc, (&value, op)| op.apply(acc, value))
+}
+
+pub fn render(values: &[Value], operations: &[&dyn Operator]) -> String {
+    let Some((first, rest)) = values.split_first() else {
+        return String::new();
+    };
+
+    let mut result = first.to_string();
+    for (value, op) in rest.iter().zip(operations) {
+        result.push_str(&format!(" {} {}", op.symbol(), value));
+    }
+    result
+}
+
+// one line per calibratable equation with the first solution found, or all of them
+pub fn explain(input: &str, operations: &[&dyn Operator], all: bool) -> String {
+    parse(input)
+        .unwrap()
+        .into_iter()
+        .filter_map(|(target, values)| {
+            let solutions = solutions(target, &values, operations);
+            let first = solutions.first()?;
+
+            let mut lines = vec![format!(
+                "{}: {} ({} solution{})",
+                target,
+                render(&values, first),
+                solutions.len(),
+                if solutions.len() == 1 { "" } else { "s" }
+            )];
+            if all {
+                for solution in &solutions[1..] {
+                    lines.push(format!("{}: {}", target, render(&values, solution)));
+                }
+            }
+
+            Some(lines.join("\n"))
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+fn total(input: &str, operations: &[&dyn Operator]) -> Value {
+    parse(input)
+        .unwrap()
+        .into_iter()
+        .filter_map(|(target, values)| solve(target, &values, operations).map(|_| target))
+        .sum()
+}
+
+pub fn part1(input: &str) -> Value {
+    total(input, PART1)
+}
+
+pub fn part2(input: &str) -> Value {
+    total(input, PART2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test::Bencher;
+
+    const TEST_INPUT: &str = include_str!("../test_inputs/day07.txt");
+
+    #[test]
+    fn test_part1() {
+        let input = TEST_INPUT.trim_ascii_end();
+        assert_eq!(part1(input), 3749);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = TEST_INPUT.trim_ascii_end();
+        assert_eq!(part2(input), 11387);
+    }
+
+    fn symbols(operations: &[&dyn Operator]) -> Vec<String> {
+        operations.iter().map(|op| op.symbol()).collect()
+    }
+
+    #[test]
+    fn test_solutions() {
+        let solution = solve(3267, &[81, 40, 27], PART1).unwrap();
+        assert_eq!(symbols(&solution), ["*", "+"]);
+        assert_eq!(solutions(3267, &[81, 40, 27], PART1).len(), 2);
+        assert!(solve(83, &[17, 5], PART2).is_none());
+        assert_eq!(
+            render(&[6, 8, 6, 15], &solve(7290, &[6, 8, 6, 15], PART2).unwrap()),
+            "6 * 8 || 6 * 15"
+        );
+        assert_eq!(solutions(4, &[1, 1, 2], PART2).len(), 2);
+
+        // zeros that wipe out or leave the left operand
+        assert_eq!(symbols(&solve(0, &[5, 0], PART1).unwrap()), ["*"]);
+        assert_eq!(symbols(&solve(7, &[0, 7], &[&CAT]).unwrap()), ["||"]);
+        assert_eq!(solutions(7, &[0, 7], PART2).len(), 2);
+        assert_eq!(solutions(4, &[2, 3, 0, 4], PART1).len(), 2);
+        assert_eq!(
+            render(&[2, 3, 0, 4], &solve(4, &[2, 3, 0, 4], PART1).unwrap()),
+            "2 + 3 * 0 + 4"
+        );
+        assert_eq!(solutions(1, &[2, 3, 0], &[&Add, &Pow]).len(), 2);
+        assert_eq!(part1("0: 5 0\n5: 5 0"), 5);
+
+        assert_eq!(evaluate(&[], &[]), None);
+        assert_eq!(render(&[], &[]), "");
+
+        let explained = explain(TEST_INPUT.trim_ascii_end(), PART1, true);
+        assert_eq!(
+            explained,
+            [
+                "190: 10 * 19 (1 solution)",
+                "3267: 81 * 40 + 27 (2 solutions)",
+                "3267: 81 + 40 * 27",
+                "292: 11 + 6 * 16 + 20 (1 solution)",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn test_operators() {
+        let binary = Cat { base: 2 };
+        let all: [&dyn Operator; 7] = [&Add, &Mul, &Sub, &Div, &Pow, &CAT, &binary];
+
+        for op in all {
+            for (left, right) in [(1, 1), (12, 3), (5, 0), (0, 7), (1000, 2), (3, 40)] {
+                // zeros can lose the left operand, e.g. 5 * 0
+                if let Some(result) = op.apply(left, right) {
+                    match op.unapply(right, result) {
+                        Some(inverse) => assert_eq!(op.apply(inverse, right), Some(result)),
+                        None => assert!(op.lossy(right, result), "{}", op.symbol()),
+                    }
+                }
+            }
+        }
+
+        assert_eq!(binary.apply(0b101, 0b11), Some(0b10111));
+        assert_eq!(
+            Pow.unapply(3, 1_000_000_000_000_000_000_000_000_000),
+            Some(10u128.pow(9))
+        );
+        assert_eq!(Pow.unapply(2, 50), None);
+        assert_eq!(CAT.apply(Value::MAX, 1), None);
+
+        let ops = ["add", "mul", "sub", "div", "pow", "cat", "cat3"]
+            .iter()
+            .map(|name| operator(name).unwrap())
+            .collect::<Vec<Box<dyn Operator>>>();
+        let ops = ops
+            .iter()
+            .map(|op| op.as_ref())
+            .collect::<Vec<&dyn Operator>>();
+        assert!(operator("cat1").is_none());
+
+        // (10 ^ 9 ^ 3 - 7) * 2, well past usize
+        let values = [10, 9, 3, 7, 2];
+        let target = 1_999_999_999_999_999_999_999_999_986;
+        let solution = solve(target, &values, &ops).unwrap();
+        assert_eq!(evaluate(&values, &solution), Some(target));
+        assert_eq!(render(&values, &solution), "10 ^ 9 ^ 3 - 7 * 2");
+
+        let big = 340_282_366_920_938_463_463_374_607_431_768_211_455;
+        assert_eq!(
+            solve(big, &[big - 5, 5], PART1).map(|s| symbols(&s)),
+            Some(vec!["+".to_string()])
+        );
+    }
+
+    #[bench]
+    fn bench_part1(b: &mut Bencher) {
+        let input = INPUT.trim_ascii_end();
+        b.iter(|| part1(input))
+    }
+
+    #[bench]
+    fn bench_part2(b: &mut Bencher) {
+        let input = INPUT.trim_ascii_end();
+        b.iter(|| part2(input))
+    }
+}
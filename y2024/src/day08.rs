@@ -0,0 +1,274 @@
+extern crate test;
+
+use std::collections::BTreeMap;
+
+use aoc_core::parse::{ParseError, utf8};
+
+pub const INPUT: &[u8] = include_bytes!("../inputs/day08.txt");
+
+pub type Pos = (isize, isize);
+
+pub struct Map {
+    pub rows: isize,
+    pub cols: isize,
+    pub antennas: BTreeMap<char, Vec<Pos>>,
+}
+
+impl Map {
+    // every character except '.' is an antenna, columns count characters rather than bytes
+    pub fn from_bytes(input: &[u8]) -> Result<Self, ParseError> {
+        let input = utf8(input)?;
+        let mut antennas: BTreeMap<char, Vec<Pos>> = BTreeMap::new();
+        let mut rows = 0;
+        let mut cols = 0;
+
+        for (row, line) in input.split('\n').enumerate() {
+            rows += 1;
+            for (col, c) in line.chars().enumerate() {
+                cols = cols.max(col as isize + 1);
+                if c != '.' {
+                    antennas
+                        .entry(c)
+                        .or_default()
+                        .push((row as isize, col as isize));
+                }
+            }
+        }
+
+        Ok(Self {
+            rows,
+            cols,
+            antennas,
+        })
+    }
+
+    pub fn contains(&self, (r, c): Pos) -> bool {
+        r >= 0 && r < self.rows && c >= 0 && c < self.cols
+    }
+}
+
+// antinodes of a pair of same frequency antennas that lie on the map
+pub trait AntinodeRule {
+    fn antinodes(&self, a: Pos, b: Pos, map: &Map) -> Vec<Pos>;
+}
+
+// steps of k times the distance between the antennas, beyond each of them
+pub struct Multiples<'a>(pub &'a [isize]);
+
+// every step along the line until it leaves the map, including the antennas themselves
+pub struct Harmonics;
+
+// points on the line where one antenna is num / den times as far as the other, inside or outside
+// the pair, kept only if they land on a cell
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Ratio {
+    num: isize,
+    den: isize,
+}
+
+pub const PART1: Multiples = Multiples(&[1]);
+pub const PART2: Harmonics = Harmonics;
+
+fn step((r, c): Pos, (dr, dc): Pos, k: isize) -> Pos {
+    (r + k * dr, c + k * dc)
+}
+
+impl AntinodeRule for Multiples<'_> {
+    fn antinodes(&self, a: Pos, b: Pos, map: &Map) -> Vec<Pos> {
+        let delta = (a.0 - b.0, a.1 - b.1);
+        self.0
+            .iter()
+            .flat_map(|&k| [step(a, delta, k), step(b, delta, -k)])
+            .filter(|&pos| map.contains(pos))
+            .collect()
+    }
+}
+
+impl AntinodeRule for Harmonics {
+    fn antinodes(&self, a: Pos, b: Pos, map: &Map) -> Vec<Pos> {
+        let delta = (a.0 - b.0, a.1 - b.1);
+        let mut result = vec![];
+        for (start, sign) in [(a, 1), (b, -1)] {
+            let mut pos = start;
+            while map.contains(pos) {
+                result.push(pos);
+                pos = step(pos, delta, sign);
+            }
+        }
+        result
+    }
+}
+
+impl Ratio {
+    // both sides have to be positive, a zero or negative distance has no antinodes to place
+    pub fn new(num: isize, den: isize) -> Option<Self> {
+        (num > 0 && den > 0).then_some(Self { num, den })
+    }
+}
+
+impl AntinodeRule for Ratio {
+    fn antinodes(&self, a: Pos, b: Pos, map: &Map) -> Vec<Pos> {
+        let delta = (b.0 - a.0, b.1 - a.1);
+        let mut ratios = vec![(self.num, self.den)];
+        if self.num != self.den {
+            ratios.push((self.den, self.num));
+        }
+
+        // p = a + t * delta with |t| * den = num * |t - 1|, one solution either side of b
+        let mut fractions = vec![];
+        for (num, den) in ratios {
+            if num != den {
+                fractions.push((num, num - den));
+            }
+            fractions.push((num, num + den));
+        }
+
+        fractions
+            .into_iter()
+            .filter(|&(p, q)| (delta.0 * p) % q == 0 && (delta.1 * p) % q == 0)
+            .map(|(p, q)| (a.0 + delta.0 * p / q, a.1 + delta.1 * p / q))
+            .filter(|&pos| map.contains(pos))
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Pair {
+    pub frequency: char,
+    pub antennas: (Pos, Pos),
+}
+
+// each antinode with the antenna pairs that produce it
+pub fn antinodes(map: &Map, rule: &dyn AntinodeRule) -> BTreeMap<Pos, Vec<Pair>> {
+    let mut result: BTreeMap<Pos, Vec<Pair>> = BTreeMap::new();
+
+    for (&frequency, positions) in &map.antennas {
+        for i in 0..positions.len() {
+            for j in i + 1..positions.len() {
+                let pair = Pair {
+                    frequency,
+                    antennas: (positions[i], positions[j]),
+                };
+                for pos in rule.antinodes(positions[i], positions[j], map) {
+                    let pairs = result.entry(pos).or_default();
+                    if pairs.last() != Some(&pair) {
+                        pairs.push(pair);
+                    }
+                }
+            }
+        }
+    }
+
+    result
+}
+
+pub fn explain(input: &[u8], rule: &dyn AntinodeRule) -> String {
+    let antinodes = antinodes(&Map::from_bytes(input).unwrap(), rule);
+    let mut lines = antinodes
+        .iter()
+        .map(|(pos, pairs)| {
+            let pairs = pairs
+                .iter()
+                .map(|p| {
+                    let ((r1, c1), (r2, c2)) = p.antennas;
+                    format!("{} {},{}-{},{}", p.frequency, r1, c1, r2, c2)
+                })
+                .collect::<Vec<String>>();
+            format!("{},{}: {}", pos.0, pos.1, pairs.join("; "))
+        })
+        .collect::<Vec<String>>();
+    lines.push(format!("{} antinodes", antinodes.len()));
+    lines.join("\n")
+}
+
+pub fn count(input: &[u8], rule: &dyn AntinodeRule) -> usize {
+    antinodes(&Map::from_bytes(input).unwrap(), rule).len()
+}
+
+pub fn parse(input: &[u8]) -> Result<Map, ParseError> {
+    Map::from_bytes(input)
+}
+
+pub fn part1(input: &[u8]) -> usize {
+    count(input, &PART1)
+}
+
+pub fn part2(input: &[u8]) -> usize {
+    count(input, &PART2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test::Bencher;
+
+    const TEST_INPUT: &[u8] = include_bytes!("../test_inputs/day08.txt");
+
+    #[test]
+    fn test_part1() {
+        let input = TEST_INPUT.trim_ascii_end();
+        assert_eq!(part1(input), 14);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = TEST_INPUT.trim_ascii_end();
+        assert_eq!(part2(input), 34);
+    }
+
+    #[test]
+    fn test_rules() {
+        let input = TEST_INPUT.trim_ascii_end();
+        assert_eq!(count(input, &Multiples(&[1, 2])), 20);
+        assert_eq!(count(input, &Ratio::new(2, 1).unwrap()), 14);
+
+        let map = Map::from_bytes(
+            b"..........\n.....\xc3\xa9....\n..........\n..........\n.........\xc3\xa9",
+        )
+        .unwrap();
+        assert_eq!(map.cols, 10);
+        assert!(matches!(parse(b"..\xc3"), Err(ParseError::Utf8)));
+        assert_eq!(map.antennas[&'\u{e9}'], [(1, 5), (4, 9)]);
+
+        let mut points = Ratio::new(2, 1).unwrap().antinodes((0, 0), (3, 3), &map);
+        points.sort();
+        assert_eq!(points, [(1, 1), (2, 2)]);
+        assert_eq!(
+            Ratio::new(1, 1).unwrap().antinodes((0, 0), (2, 4), &map),
+            [(1, 2)]
+        );
+        assert_eq!(Harmonics.antinodes((0, 0), (2, 4), &map).len(), 3);
+
+        assert_eq!(Ratio::new(0, 0), None);
+        assert_eq!(Ratio::new(2, 0), None);
+        assert_eq!(Ratio::new(-2, 1), None);
+
+        let explained = explain(b"....\n.#..\n..#.\n....", &PART1);
+        assert_eq!(explained, "0,0: # 1,1-2,2\n3,3: # 1,1-2,2\n2 antinodes");
+    }
+
+    #[test]
+    fn test_sources() {
+        let input = TEST_INPUT.trim_ascii_end();
+        let antinodes = antinodes(&Map::from_bytes(input).unwrap(), &PART1);
+        assert_eq!(
+            antinodes[&(5, 6)],
+            [Pair {
+                frequency: '0',
+                antennas: ((1, 8), (3, 7))
+            }]
+        );
+    }
+
+    #[bench]
+    fn bench_part1(b: &mut Bencher) {
+        let input = INPUT.trim_ascii_end();
+        b.iter(|| part1(input))
+    }
+
+    #[bench]
+    fn bench_part2(b: &mut Bencher) {
+        let input = INPUT.trim_ascii_end();
+        b.iter(|| part2(input))
+    }
+}
@@ -1,6 +1,22 @@
 extern crate test;
 
-const INPUT: &[u8] = include_bytes!("../inputs/day09.txt");
+use aoc_core::parse::ParseError;
+
+pub const INPUT: &[u8] = include_bytes!("../inputs/day09.txt");
+
+// alternating file and free space lengths, one digit each
+pub fn parse(input: &[u8]) -> Result<Vec<usize>, ParseError> {
+    if input.is_empty() {
+        return Err(ParseError::Empty);
+    }
+    input
+        .iter()
+        .map(|&b| match b {
+            b'0'..=b'9' => Ok((b - b'0') as usize),
+            _ => Err(ParseError::InvalidByte(b)),
+        })
+        .collect()
+}
 
 pub fn part1(input: &[u8]) -> usize {
     let mut filesystem = Vec::new();
@@ -9,18 +25,19 @@ pub fn part1(input: &[u8]) -> usize {
 
     let mut cur_file = 0;
 
-    let mut iter = input.iter();
+    let lengths = parse(input).unwrap();
+    let mut iter = lengths.iter();
     let mut cur_pos = 0;
 
-    while let Some(byte) = iter.next() {
-        for _ in 0..(byte - b'0') as usize {
+    while let Some(&len) = iter.next() {
+        for _ in 0..len {
             filesystem.push(Some(cur_file));
             non_empty_stack.push(cur_pos);
             cur_pos += 1;
         }
 
-        if let Some(byte) = iter.next() {
-            for _ in 0..(byte - b'0') as usize {
+        if let Some(&len) = iter.next() {
+            for _ in 0..len {
                 filesystem.push(None);
                 empty_stack.push(cur_pos);
                 cur_pos += 1;
@@ -63,17 +80,16 @@ pub fn part2(input: &[u8]) -> usize {
 
     let mut cur_file = 0;
 
-    let mut iter = input.iter();
+    let lengths = parse(input).unwrap();
+    let mut iter = lengths.iter();
     let mut cur_pos = 0;
 
-    while let Some(byte) = iter.next() {
-        let count = (byte - b'0') as usize;
+    while let Some(&count) = iter.next() {
         files.push((cur_file, cur_pos, count));
 
         cur_pos += count;
 
-        if let Some(byte) = iter.next() {
-            let count = (byte - b'0') as usize;
+        if let Some(&count) = iter.next() {
             cur_pos += count;
         } else {
             break;
@@ -128,13 +144,6 @@ pub fn part2(input: &[u8]) -> usize {
     result
 }
 
-pub fn main() {
-    let input = INPUT.trim_ascii_end();
-
-    println!("{}", part1(input));
-    println!("{}", part2(input));
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,87 @@
+extern crate test;
+
+use aoc_core::grid::Grid;
+use aoc_core::parse::ParseError;
+use aoc_core::search;
+
+pub const INPUT: &[u8] = include_bytes!("../inputs/day10.txt");
+
+// heights from 0 to 9
+pub fn parse(bytes: &[u8]) -> Result<Grid, ParseError> {
+    Grid::parse(bytes, |b| match b {
+        b'0'..=b'9' => Ok(b - b'0'),
+        _ => Err(ParseError::InvalidByte(b)),
+    })
+}
+
+fn get_zeros(grid: &Grid) -> impl Iterator<Item = (usize, usize)> {
+    grid.cells
+        .iter()
+        .enumerate()
+        .filter(|&(_, c)| *c == 0)
+        .map(|(i, _)| grid.coords(i))
+}
+
+fn is_final(grid: &Grid, coords: (usize, usize)) -> bool {
+    grid[coords] == 9
+}
+
+fn next(grid: &Grid, coords: (usize, usize)) -> Vec<(usize, usize)> {
+    let val = grid[coords];
+    grid.neighbours(coords)
+        .filter(|&n| grid[n] == val + 1)
+        .collect()
+}
+
+// the tops each trailhead reaches
+pub fn part1(input: &[u8]) -> usize {
+    let grid = parse(input).unwrap();
+    get_zeros(&grid)
+        .map(|start| {
+            search::bfs(start, |&n| next(&grid, n))
+                .into_keys()
+                .filter(|&n| is_final(&grid, n))
+                .count()
+        })
+        .sum()
+}
+
+// the distinct trails from each trailhead to a top
+pub fn part2(input: &[u8]) -> usize {
+    let grid = parse(input).unwrap();
+    get_zeros(&grid)
+        .map(|start| search::count_paths(start, |&n| next(&grid, n), |&n| is_final(&grid, n)))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test::Bencher;
+
+    const TEST_INPUT: &[u8] = include_bytes!("../test_inputs/day10.txt");
+
+    #[test]
+    fn test_part1() {
+        let input = TEST_INPUT.trim_ascii_end();
+        assert_eq!(part1(input), 36);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = TEST_INPUT.trim_ascii_end();
+        assert_eq!(part2(input), 81);
+    }
+
+    #[bench]
+    fn bench_part1(b: &mut Bencher) {
+        let input = INPUT.trim_ascii_end();
+        b.iter(|| part1(input))
+    }
+
+    #[bench]
+    fn bench_part2(b: &mut Bencher) {
+        let input = INPUT.trim_ascii_end();
+        b.iter(|| part2(input))
+    }
+}
@@ -1,8 +1,9 @@
+use aoc_core::parse::{ParseError, integer};
 use memoize::memoize;
 
 extern crate test;
 
-const INPUT: &str = include_str!("../inputs/day11.txt");
+pub const INPUT: &str = include_str!("../inputs/day11.txt");
 
 #[memoize]
 fn process(number: usize, times: usize) -> usize {
@@ -24,27 +25,27 @@ fn process(number: usize, times: usize) -> usize {
     }
 }
 
+// stones separated by single spaces
+pub fn parse(input: &str) -> Result<Vec<usize>, ParseError> {
+    input.split(' ').map(|s| integer(s.as_bytes())).collect()
+}
+
 pub fn part1(input: &str) -> usize {
-    input
-        .split(' ')
-        .map(|str| process(str.parse::<usize>().unwrap(), 25))
+    parse(input)
+        .unwrap()
+        .into_iter()
+        .map(|number| process(number, 25))
         .sum()
 }
 
 pub fn part2(input: &str) -> usize {
-    input
-        .split(' ')
-        .map(|str| process(str.parse::<usize>().unwrap(), 75))
+    parse(input)
+        .unwrap()
+        .into_iter()
+        .map(|number| process(number, 75))
         .sum()
 }
 
-pub fn main() {
-    let input = INPUT.trim_ascii_end();
-
-    println!("{}", part1(input));
-    println!("{}", part2(input));
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,190 @@
+extern crate test;
+
+use std::collections::HashSet;
+use std::collections::VecDeque;
+
+use aoc_core::grid::Grid;
+use aoc_core::parse::ParseError;
+
+pub const INPUT: &[u8] = include_bytes!("../inputs/day12.txt");
+
+// the cell one step in the given direction, as (index, plant)
+fn neighbour(grid: &Grid, index: usize, (dr, dc): (isize, isize)) -> Option<(usize, u8)> {
+    let (row, col) = grid.coords(index);
+    let pos = (row as isize + dr, col as isize + dc);
+    grid.get(pos)
+        .map(|&v| (grid.index_of((pos.0 as usize, pos.1 as usize)), v))
+}
+
+fn flood(grid: &Grid, index: usize) -> (HashSet<usize>, usize, usize, usize) {
+    let mut visited = HashSet::new();
+    let mut corner_counts: Vec<usize> = vec![0; 5];
+    let mut area = 0;
+    let mut perimeter = 0;
+
+    let mut queue = VecDeque::from([index]);
+
+    while let Some(ix) = queue.pop_front() {
+        if visited.contains(&ix) {
+            continue;
+        }
+
+        visited.insert(ix);
+        area += 1;
+
+        let value = grid.cells[ix];
+
+        let up = neighbour(grid, ix, (-1, 0));
+        let down = neighbour(grid, ix, (1, 0));
+        let left = neighbour(grid, ix, (0, -1));
+        let right = neighbour(grid, ix, (0, 1));
+
+        let neighs = [up, down, left, right]
+            .into_iter()
+            .flatten()
+            .filter(|&(_, v)| v == value)
+            .collect::<Vec<_>>();
+
+        perimeter += 4 - neighs.len();
+
+        match neighs.len() {
+            0 => corner_counts[4] += 1,
+            1 => corner_counts[3] += 1,
+            2 => match neighs[0].0.abs_diff(neighs[1].0) {
+                val if val == 2 * grid.cols || val == 2 => {}
+                _ => corner_counts[2] += 1,
+            },
+            _ => {}
+        };
+
+        match up {
+            Some((_, v)) if v == value => {
+                match left {
+                    Some((_, v)) if v == value => match neighbour(grid, ix, (-1, -1)) {
+                        Some((_, v)) if v != value => corner_counts[2] += 1,
+                        _ => {}
+                    },
+                    _ => {}
+                };
+                match right {
+                    Some((_, v)) if v == value => match neighbour(grid, ix, (-1, 1)) {
+                        Some((_, v)) if v != value => corner_counts[2] += 1,
+                        _ => {}
+                    },
+                    _ => {}
+                };
+            }
+            _ => {}
+        };
+
+        match down {
+            Some((_, v)) if v == value => {
+                match left {
+                    Some((_, v)) if v == value => match neighbour(grid, ix, (1, -1)) {
+                        Some((_, v)) if v != value => corner_counts[2] += 1,
+                        _ => {}
+                    },
+                    _ => {}
+                };
+                match right {
+                    Some((_, v)) if v == value => match neighbour(grid, ix, (1, 1)) {
+                        Some((_, v)) if v != value => corner_counts[2] += 1,
+                        _ => {}
+                    },
+                    _ => {}
+                };
+            }
+            _ => {}
+        };
+
+        for (neigh, _) in neighs {
+            if !visited.contains(&neigh) {
+                queue.push_back(neigh);
+            }
+        }
+    }
+
+    let sides = if corner_counts[4] > 0 {
+        4
+    } else {
+        2 * corner_counts[2] + 3 * corner_counts[3] - corner_counts[2] - corner_counts[3]
+    };
+
+    (visited, area, perimeter, sides)
+}
+
+// plots labelled with their plant type
+pub fn parse(input: &[u8]) -> Result<Grid, ParseError> {
+    Grid::from_bytes(input)
+}
+
+pub fn part1(input: &[u8]) -> usize {
+    let grid = parse(input).unwrap();
+
+    let mut result = 0;
+
+    let mut visited = HashSet::new();
+    for index in 0..grid.cells.len() {
+        if visited.contains(&index) {
+            continue;
+        }
+
+        let (visited_new, area, perimeter, _) = flood(&grid, index);
+        visited.extend(visited_new);
+        result += area * perimeter;
+    }
+
+    result
+}
+
+pub fn part2(input: &[u8]) -> usize {
+    let grid = parse(input).unwrap();
+
+    let mut result = 0;
+
+    let mut visited = HashSet::new();
+    for index in 0..grid.cells.len() {
+        if visited.contains(&index) {
+            continue;
+        }
+
+        let (visited_new, area, _, sides) = flood(&grid, index);
+
+        visited.extend(visited_new);
+        result += area * sides;
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test::Bencher;
+
+    const TEST_INPUT: &[u8] = include_bytes!("../test_inputs/day12.txt");
+
+    #[test]
+    fn test_part1() {
+        let input = TEST_INPUT.trim_ascii_end();
+        assert_eq!(part1(input), 1930);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = TEST_INPUT.trim_ascii_end();
+        assert_eq!(part2(input), 1206);
+    }
+
+    #[bench]
+    fn bench_part1(b: &mut Bencher) {
+        let input = INPUT.trim_ascii_end();
+        b.iter(|| part1(input))
+    }
+
+    #[bench]
+    fn bench_part2(b: &mut Bencher) {
+        let input = INPUT.trim_ascii_end();
+        b.iter(|| part2(input))
+    }
+}
@@ -0,0 +1,158 @@
+extern crate test;
+
+use aoc_core::parse::{ParseError, integer};
+
+pub const INPUT: &[u8] = include_bytes!("../inputs/day13.txt");
+
+const COST_A: usize = 3;
+const COST_B: usize = 1;
+
+const OFFSET: isize = 10000000000000;
+
+// button a, button b and the prize
+pub type Machine = [(isize, isize); 3];
+
+// numbers stay below 2^16 so that solve can't overflow, even with the part 2 offset
+fn pair_from_bytes(bytes: &[u8]) -> Result<(isize, isize), ParseError> {
+    let mut iter = bytes.split(|&b| b == b',');
+    let mut number = || {
+        let part = iter.next().ok_or(ParseError::Missing("','"))?;
+        let digits = part
+            .split(|&b| b == b'+' || b == b'=')
+            .nth(1)
+            .ok_or(ParseError::Missing("'+' or '='"))?;
+        integer::<u16>(digits).map(|n| n as isize)
+    };
+
+    Ok((number()?, number()?))
+}
+
+// three lines per machine, machines separated by an empty line
+pub fn parse(input: &[u8]) -> Result<Vec<Machine>, ParseError> {
+    let mut lines = input.split(|&b| b == b'\n');
+    let mut machines = vec![];
+
+    loop {
+        let mut pair = || pair_from_bytes(lines.next().ok_or(ParseError::Missing("line"))?);
+        let machine = [pair()?, pair()?, pair()?];
+
+        let [(a_x, a_y), (b_x, b_y), _] = machine;
+        if a_x * b_y == a_y * b_x {
+            return Err(ParseError::Invalid(
+                "buttons move the claw along the same line",
+            ));
+        }
+        machines.push(machine);
+
+        match lines.next() {
+            None => return Ok(machines),
+            Some([]) => {}
+            Some(_) => return Err(ParseError::Missing("empty line")),
+        }
+    }
+}
+
+/*
+ *
+ *  i * a1 + j * b1 = p1
+ *  i * a2 + j * b2 = p2
+ *
+ * i * (a1 * a2) + j * (a2 * b1) = a2 * p1
+ * i * (a1 * a2) + j * (a1 * b2) = a1 * p2
+ *
+ * j * [ a1 * b2 - a2 * b1 ] = a1 * p2 - a2 * p1
+ *
+ * j = [ a1 * p2 - a2 * p1 ] / [ a1 * b2 - a2 * b1 ]
+ *
+ * i = [ p1 - j * b1 ] / a1
+ *
+ * i = p1 / a1 - [ b1 * p2 - b1 * a2 * p1 / a1 ] / [ a1 * b2 - a2 * b1 ]
+ *
+ * i = [ b2 * p1 - b1 * p2 ] / [ a1 * b2 - a2 * b1 ]
+ *
+ *
+ * */
+
+pub fn solve(input: &[u8], offset: isize) -> usize {
+    let mut result = 0;
+
+    for [(a_x, a_y), (b_x, b_y), (p_x, p_y)] in parse(input).unwrap() {
+        let (p_x, p_y) = (p_x + offset, p_y + offset);
+
+        let det = a_x * b_y - a_y * b_x;
+        let alpha = b_y * p_x - b_x * p_y;
+        let beta = a_x * p_y - a_y * p_x;
+
+        if alpha % det == 0 && beta % det == 0 {
+            let i = alpha / det;
+            let j = beta / det;
+
+            if i >= 0 && j >= 0 {
+                result += i as usize * COST_A;
+                result += j as usize * COST_B;
+            }
+        }
+    }
+
+    result
+}
+
+pub fn part1(input: &[u8]) -> usize {
+    solve(input, 0)
+}
+
+pub fn part2(input: &[u8]) -> usize {
+    solve(input, OFFSET)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test::Bencher;
+
+    const TEST_INPUT: &[u8] = include_bytes!("../test_inputs/day13.txt");
+
+    #[test]
+    fn test_part1() {
+        let input = TEST_INPUT.trim_ascii_end();
+        assert_eq!(part1(input), 480);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = TEST_INPUT.trim_ascii_end();
+        assert_eq!(part2(input), 875318608908);
+    }
+
+    #[test]
+    fn test_parse() {
+        let machine = b"Button A: X+1, Y+2\nButton B: X+3, Y+4\nPrize: X=5, Y=6";
+        assert_eq!(parse(machine), Ok(vec![[(1, 2), (3, 4), (5, 6)]]));
+        assert_eq!(
+            parse(b"Button A: X+1, Y+2\nButton B: X+2, Y+4\nPrize: X=3, Y=6"),
+            Err(ParseError::Invalid(
+                "buttons move the claw along the same line"
+            ))
+        );
+        assert_eq!(
+            parse(b"Button A: X+1, Y+2\nButton B: X+3, Y+4"),
+            Err(ParseError::Missing("line"))
+        );
+        assert_eq!(
+            parse(b"Button A: X+1, Y+2\nButton B: X+3, Y+4\nPrize: X=70000, Y=6"),
+            Err(ParseError::Overflow)
+        );
+    }
+
+    #[bench]
+    fn bench_part1(b: &mut Bencher) {
+        let input = INPUT.trim_ascii_end();
+        b.iter(|| part1(input))
+    }
+
+    #[bench]
+    fn bench_part2(b: &mut Bencher) {
+        let input = INPUT.trim_ascii_end();
+        b.iter(|| part2(input))
+    }
+}
@@ -2,29 +2,40 @@ extern crate test;
 
 use std::collections::HashSet;
 
-const INPUT: &str = include_str!("../inputs/day14.txt");
+use aoc_core::cancel::{Cancel, Cancelled};
+use aoc_core::parse::{ParseError, integer};
+
+pub const INPUT: &str = include_str!("../inputs/day14.txt");
 
 const WIDTH: isize = 101;
 const HEIGHT: isize = 103;
 
 #[derive(Debug)]
-struct Robot {
+pub struct Robot {
     pos: (isize, isize),
     vel: (isize, isize),
 }
 
+// 32 bit values, so a hundred steps can't overflow
+fn pair(s: &str) -> Result<(isize, isize), ParseError> {
+    let (x, y) = s.split_once(',').ok_or(ParseError::Missing("','"))?;
+    let x = integer::<i32>(x.as_bytes())?;
+    let y = integer::<i32>(y.as_bytes())?;
+    Ok((x as isize, y as isize))
+}
+
 impl Robot {
-    fn from_line(line: &str) -> Self {
-        let mut iter = line.split(['=', ',', ' ']);
-        let px = iter.nth(1).unwrap().parse().unwrap();
-        let py = iter.next().unwrap().parse().unwrap();
-        let vx = iter.nth(1).unwrap().parse().unwrap();
-        let vy = iter.next().unwrap().parse().unwrap();
-
-        Self {
-            pos: (px, py),
-            vel: (vx, vy),
-        }
+    // p=x,y v=x,y
+    fn from_line(line: &str) -> Result<Self, ParseError> {
+        let (pos, vel) = line
+            .strip_prefix("p=")
+            .and_then(|line| line.split_once(" v="))
+            .ok_or(ParseError::Missing("'p=' or ' v='"))?;
+
+        Ok(Self {
+            pos: pair(pos)?,
+            vel: pair(vel)?,
+        })
     }
 
     fn advance(&mut self, steps: isize, bounds: (isize, isize)) {
@@ -58,13 +69,14 @@ impl Robot {
     }
 }
 
-fn part1_with_bounds(input: &str, width: isize, height: isize) -> usize {
+pub fn parse(input: &str) -> Result<Vec<Robot>, ParseError> {
+    input.lines().map(Robot::from_line).collect()
+}
+
+pub fn part1_with_bounds(input: &str, width: isize, height: isize) -> usize {
     let mut counts = [0; 4];
-    for line in input.lines() {
-        let mut robot = Robot::from_line(line);
-        println!("{:?}", robot);
+    for mut robot in parse(input).unwrap() {
         robot.advance(100, (width, height));
-        println!("{:?}", robot);
         if let Some(quadrant) = robot.get_quadrant((width, height)) {
             counts[quadrant] += 1;
         }
@@ -72,12 +84,18 @@ fn part1_with_bounds(input: &str, width: isize, height: isize) -> usize {
     counts.iter().product()
 }
 
-pub fn part2_with_bounds(input: &str, width: isize, height: isize) -> usize {
-    let mut robots: Vec<Robot> = input.lines().map(Robot::from_line).collect();
-    let mut steps = 0;
+// positions repeat every width * height steps, so none if no frame up to then has every robot on
+// its own tile
+pub fn part2_with_bounds(
+    input: &str,
+    width: isize,
+    height: isize,
+    cancel: &Cancel,
+) -> Result<Option<usize>, Cancelled> {
+    let mut robots = parse(input).unwrap();
 
-    loop {
-        steps += 1;
+    for steps in 1..=(width * height) as usize {
+        cancel.check()?;
 
         let mut distinct_positions = HashSet::new();
         for robot in robots.iter_mut() {
@@ -86,24 +104,23 @@ pub fn part2_with_bounds(input: &str, width: isize, height: isize) -> usize {
         }
 
         if distinct_positions.len() == robots.len() {
-            return steps;
+            return Ok(Some(steps));
         }
     }
+
+    Ok(None)
 }
 
 pub fn part1(input: &str) -> usize {
     part1_with_bounds(input, WIDTH, HEIGHT)
 }
 
-pub fn part2(input: &str) -> usize {
-    part2_with_bounds(input, WIDTH, HEIGHT)
+pub fn part2_with_cancel(input: &str, cancel: &Cancel) -> Result<Option<usize>, Cancelled> {
+    part2_with_bounds(input, WIDTH, HEIGHT, cancel)
 }
 
-pub fn main() {
-    let input = INPUT.trim_ascii_end();
-
-    println!("{}", part1(input));
-    println!("{}", part2(input));
+pub fn part2(input: &str) -> Option<usize> {
+    part2_with_cancel(input, &Cancel::default()).unwrap()
 }
 
 #[cfg(test)]
@@ -125,7 +142,19 @@ mod tests {
     #[test]
     fn test_part2() {
         let input = TEST_INPUT.trim_ascii_end();
-        assert_eq!(part2_with_bounds(input, WIDTH_TEST, HEIGHT_TEST), 1);
+        assert_eq!(
+            part2_with_bounds(input, WIDTH_TEST, HEIGHT_TEST, &Cancel::default()),
+            Ok(Some(1))
+        );
+        assert_eq!(
+            part2_with_bounds(
+                "p=0,4 v=3,-3\np=0,4 v=3,-3",
+                WIDTH_TEST,
+                HEIGHT_TEST,
+                &Cancel::default()
+            ),
+            Ok(None)
+        );
     }
 
     #[bench]
@@ -3,10 +3,13 @@ extern crate test;
 use std::collections::HashSet;
 use std::collections::VecDeque;
 
-const INPUT: &[u8] = include_bytes!("../inputs/day15.txt");
+use aoc_core::grid::Grid;
+use aoc_core::parse::ParseError;
+
+pub const INPUT: &[u8] = include_bytes!("../inputs/day15.txt");
 
 #[derive(Debug, Clone, Copy)]
-enum Instruction {
+pub enum Instruction {
     Left,
     Right,
     Up,
@@ -14,13 +17,13 @@ enum Instruction {
 }
 
 impl Instruction {
-    fn from_byte(b: u8) -> Self {
+    fn from_byte(b: u8) -> Option<Self> {
         match b {
-            b'<' => Self::Left,
-            b'>' => Self::Right,
-            b'^' => Self::Up,
-            b'v' => Self::Down,
-            _ => panic!("invalid byte"),
+            b'<' => Some(Self::Left),
+            b'>' => Some(Self::Right),
+            b'^' => Some(Self::Up),
+            b'v' => Some(Self::Down),
+            _ => None,
         }
     }
 
@@ -35,7 +38,7 @@ impl Instruction {
 }
 
 #[derive(Debug, Clone, Copy)]
-enum Cell {
+pub enum Cell {
     Wall,
     Box,
     Empty,
@@ -50,57 +53,64 @@ enum WideCell {
 }
 
 #[derive(Debug)]
-struct Grid {
-    cols: usize,
-    cells: Vec<Cell>,
+struct Warehouse {
+    grid: Grid<Cell>,
     robot: (isize, isize),
 }
 
 #[derive(Debug, Clone)]
-struct WideGrid {
-    cols: usize,
-    cells: Vec<WideCell>,
+struct WideWarehouse {
+    grid: Grid<WideCell>,
     robot: (isize, isize),
 }
 
-impl Grid {
-    fn from_bytes(bytes: &[u8]) -> Self {
-        let lines = bytes.split(|&b| b == b'\n');
-        let mut cells = vec![];
-        let mut robot = (0, 0);
-
-        for (row, line) in lines.enumerate() {
-            if line.is_empty() {
-                break;
-            }
+// the map, where the robot starts and its moves
+type Plan = (Grid<Cell>, (isize, isize), Vec<Instruction>);
+
+// the map up to the first empty line, walled in on every side, then the moves, which may be
+// split over several lines
+pub fn parse(input: &[u8]) -> Result<Plan, ParseError> {
+    let end = input
+        .windows(2)
+        .position(|w| w == b"\n\n")
+        .unwrap_or(input.len());
+
+    let plan = Grid::parse(&input[..end], |b| match b {
+        b'#' | b'O' | b'.' | b'@' => Ok(b),
+        _ => Err(ParseError::InvalidByte(b)),
+    })?;
+    let robot = plan
+        .position(|&b| b == b'@')
+        .ok_or(ParseError::Missing("robot"))?;
+
+    if !plan.border().all(|&b| b == b'#') {
+        return Err(ParseError::Invalid("the map isn't walled in"));
+    }
 
-            for (col, b) in line.iter().enumerate() {
-                match b {
-                    b'#' => cells.push(Cell::Wall),
-                    b'O' => cells.push(Cell::Box),
-                    b'.' => cells.push(Cell::Empty),
-                    b'@' => {
-                        cells.push(Cell::Empty);
-                        robot = (row as isize, col as isize)
-                    }
-                    _ => panic!("invalid byte"),
-                }
-            }
-        }
+    let grid = plan.map(|&b| match b {
+        b'#' => Cell::Wall,
+        b'O' => Cell::Box,
+        _ => Cell::Empty,
+    });
 
-        let cols = bytes.iter().take_while(|&&b| b != b'\n').count();
+    let instructions = input[end..]
+        .iter()
+        .filter(|&&b| b != b'\n')
+        .map(|&b| Instruction::from_byte(b).ok_or(ParseError::InvalidByte(b)))
+        .collect::<Result<_, _>>()?;
 
-        Self { cols, cells, robot }
-    }
+    Ok((grid, (robot.0 as isize, robot.1 as isize), instructions))
+}
 
+impl Warehouse {
     fn score(&self) -> usize {
-        self.cells
+        self.grid
+            .cells
             .iter()
             .enumerate()
             .map(|(ix, c)| match c {
                 Cell::Box => {
-                    let row = ix / self.cols;
-                    let col = ix % self.cols;
+                    let (row, col) = self.grid.coords(ix);
                     100 * row + col
                 }
                 _ => 0,
@@ -109,11 +119,11 @@ impl Grid {
     }
 
     fn get(&self, pos: (isize, isize)) -> Cell {
-        self.cells[pos.0 as usize * self.cols + pos.1 as usize]
+        self.grid[(pos.0 as usize, pos.1 as usize)]
     }
 
     fn set(&mut self, pos: (isize, isize), val: Cell) {
-        self.cells[pos.0 as usize * self.cols + pos.1 as usize] = val
+        self.grid[(pos.0 as usize, pos.1 as usize)] = val
     }
 
     fn next_empty_pos(&self, pos: (isize, isize), dir: (isize, isize)) -> Option<(isize, isize)> {
@@ -143,54 +153,37 @@ impl Grid {
     }
 }
 
-impl WideGrid {
-    fn from_bytes(bytes: &[u8]) -> Self {
-        let lines = bytes.split(|&b| b == b'\n');
-        let mut cells = vec![];
-        let mut robot = (0, 0);
-
-        for (row, line) in lines.enumerate() {
-            if line.is_empty() {
-                break;
-            }
-
-            for (col, b) in line.iter().enumerate() {
-                match b {
-                    b'#' => {
-                        cells.push(WideCell::Wall);
-                        cells.push(WideCell::Wall);
-                    }
-                    b'O' => {
-                        cells.push(WideCell::BoxLeft);
-                        cells.push(WideCell::BoxRight);
-                    }
-                    b'.' => {
-                        cells.push(WideCell::Empty);
-                        cells.push(WideCell::Empty);
-                    }
-                    b'@' => {
-                        cells.push(WideCell::Empty);
-                        cells.push(WideCell::Empty);
-                        robot = (row as isize, 2 * col as isize)
-                    }
-                    _ => panic!("invalid byte"),
-                }
-            }
+impl WideWarehouse {
+    fn new(grid: &Grid<Cell>, robot: (isize, isize)) -> Self {
+        let cells = grid
+            .cells
+            .iter()
+            .flat_map(|c| match c {
+                Cell::Wall => [WideCell::Wall, WideCell::Wall],
+                Cell::Box => [WideCell::BoxLeft, WideCell::BoxRight],
+                Cell::Empty => [WideCell::Empty, WideCell::Empty],
+            })
+            .collect();
+        let grid = Grid {
+            rows: grid.rows,
+            cols: 2 * grid.cols,
+            cells,
+        };
+
+        Self {
+            grid,
+            robot: (robot.0, 2 * robot.1),
         }
-
-        let cols = 2 * bytes.iter().take_while(|&&b| b != b'\n').count();
-
-        Self { cols, cells, robot }
     }
 
     fn score(&self) -> usize {
-        self.cells
+        self.grid
+            .cells
             .iter()
             .enumerate()
             .map(|(ix, c)| match c {
                 WideCell::BoxLeft => {
-                    let row = ix / self.cols;
-                    let col = ix % self.cols;
+                    let (row, col) = self.grid.coords(ix);
                     100 * row + col
                 }
                 _ => 0,
@@ -199,11 +192,11 @@ impl WideGrid {
     }
 
     fn get(&self, pos: (isize, isize)) -> WideCell {
-        self.cells[pos.0 as usize * self.cols + pos.1 as usize]
+        self.grid[(pos.0 as usize, pos.1 as usize)]
     }
 
     fn set(&mut self, pos: (isize, isize), val: WideCell) {
-        self.cells[pos.0 as usize * self.cols + pos.1 as usize] = val
+        self.grid[(pos.0 as usize, pos.1 as usize)] = val
     }
 
     fn next_empty_pos(&self, pos: (isize, isize), dir: (isize, isize)) -> Option<(isize, isize)> {
@@ -314,38 +307,23 @@ impl WideGrid {
 }
 
 pub fn part1(input: &[u8]) -> usize {
-    let mut grid = Grid::from_bytes(input);
+    let (grid, robot, instructions) = parse(input).unwrap();
+    let mut warehouse = Warehouse { grid, robot };
 
-    let instructions = input
-        .split(|&b| b == b'\n')
-        .skip_while(|&line| !line.is_empty())
-        .skip(1)
-        .flat_map(|line| line.iter().map(|&b| Instruction::from_byte(b)));
     for instruction in instructions {
-        grid.move_robot(instruction.get_dir());
+        warehouse.move_robot(instruction.get_dir());
     }
-    grid.score()
+    warehouse.score()
 }
 
 pub fn part2(input: &[u8]) -> usize {
-    let mut grid = WideGrid::from_bytes(input);
+    let (grid, robot, instructions) = parse(input).unwrap();
+    let mut warehouse = WideWarehouse::new(&grid, robot);
 
-    let instructions = input
-        .split(|&b| b == b'\n')
-        .skip_while(|&line| !line.is_empty())
-        .skip(1)
-        .flat_map(|line| line.iter().map(|&b| Instruction::from_byte(b)));
     for instruction in instructions {
-        grid.move_robot(instruction.get_dir());
+        warehouse.move_robot(instruction.get_dir());
     }
-    grid.score()
-}
-
-pub fn main() {
-    let input = INPUT.trim_ascii_end();
-
-    println!("{}", part1(input));
-    println!("{}", part2(input));
+    warehouse.score()
 }
 
 #[cfg(test)]
@@ -374,6 +352,26 @@ mod tests {
         assert_eq!(part2(input), 9021);
     }
 
+    #[test]
+    fn test_parse() {
+        let (grid, robot, instructions) = parse(b"####\n#@O#\n####\n\n<v\n>").unwrap();
+        assert_eq!((grid.rows, grid.cols, robot), (3, 4, (1, 1)));
+        assert_eq!(instructions.len(), 3);
+
+        assert!(matches!(
+            parse(b"#.#\n#@#\n###"),
+            Err(ParseError::Invalid("the map isn't walled in"))
+        ));
+        assert!(matches!(
+            parse(b"###\n#.#\n###"),
+            Err(ParseError::Missing("robot"))
+        ));
+        assert!(matches!(
+            parse(b"###\n#@#\n###\n\n<x"),
+            Err(ParseError::InvalidByte(b'x'))
+        ));
+    }
+
     #[bench]
     fn bench_part1(b: &mut Bencher) {
         let input = INPUT.trim_ascii_end();
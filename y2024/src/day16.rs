@@ -1,13 +1,15 @@
 extern crate test;
 
-use std::cmp::Ordering;
-use std::collections::BinaryHeap;
 use std::collections::HashSet;
 use std::collections::VecDeque;
 
-const INPUT: &[u8] = include_bytes!("../inputs/day16.txt");
+use aoc_core::grid::Grid;
+use aoc_core::parse::ParseError;
+use aoc_core::search;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub const INPUT: &[u8] = include_bytes!("../inputs/day16.txt");
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 enum Dir {
     Left,
     Right,
@@ -54,60 +56,56 @@ impl Dir {
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Cell {
     Wall,
     Empty,
 }
 
 #[derive(Debug)]
-struct Grid {
-    cols: usize,
-    cells: Vec<Cell>,
+pub struct Maze {
+    grid: Grid<Cell>,
     start: (isize, isize),
     end: (isize, isize),
 }
 
-impl Grid {
-    fn from_bytes(bytes: &[u8]) -> Self {
-        let mut cells = vec![];
-        let mut start = (0, 0);
-        let mut end = (0, 0);
-
-        for (row, line) in bytes.split(|&b| b == b'\n').enumerate() {
-            for (col, b) in line.iter().enumerate() {
-                match b {
-                    b'#' => cells.push(Cell::Wall),
-                    b'.' => cells.push(Cell::Empty),
-                    b'S' => {
-                        cells.push(Cell::Empty);
-                        start = (row as isize, col as isize)
-                    }
-                    b'E' => {
-                        cells.push(Cell::Empty);
-                        end = (row as isize, col as isize)
-                    }
-                    _ => panic!("invalid byte: {}", *b as char),
-                }
-            }
-        }
+// one start, one end and a way between them, walled in on every side
+pub fn parse(input: &[u8]) -> Result<Maze, ParseError> {
+    let plan = Grid::parse(input, |b| match b {
+        b'#' | b'.' | b'S' | b'E' => Ok(b),
+        _ => Err(ParseError::InvalidByte(b)),
+    })?;
+    if !plan.border().all(|&b| b == b'#') {
+        return Err(ParseError::Invalid("the maze isn't walled in"));
+    }
 
-        let cols = bytes.iter().take_while(|&&b| b != b'\n').count();
+    let find = |c, what| plan.position(|&b| b == c).ok_or(ParseError::Missing(what));
+    let start = find(b'S', "start")?;
+    let end = find(b'E', "end")?;
+    let grid = plan.map(|&b| match b {
+        b'#' => Cell::Wall,
+        _ => Cell::Empty,
+    });
 
-        Self {
-            cols,
-            cells,
-            start,
-            end,
-        }
+    let reachable = search::bfs(start, |&pos| {
+        grid.neighbours(pos)
+            .filter(|&n| grid[n] == Cell::Empty)
+            .collect::<Vec<_>>()
+    });
+    if !reachable.contains_key(&end) {
+        return Err(ParseError::Invalid("the end can't be reached"));
     }
 
-    fn get(&self, pos: (isize, isize)) -> Cell {
-        self.cells[self.to_index(pos)]
-    }
+    Ok(Maze {
+        grid,
+        start: (start.0 as isize, start.1 as isize),
+        end: (end.0 as isize, end.1 as isize),
+    })
+}
 
-    fn to_index(&self, pos: (isize, isize)) -> usize {
-        pos.0 as usize * self.cols + pos.1 as usize
+impl Maze {
+    fn get(&self, pos: (isize, isize)) -> Cell {
+        self.grid[(pos.0 as usize, pos.1 as usize)]
     }
 
     fn next(&self, pos: (isize, isize), dir: Dir) -> Option<(isize, isize)> {
@@ -126,113 +124,34 @@ impl Grid {
     }
 }
 
-#[derive(Copy, Clone, Eq, PartialEq)]
-struct State {
-    cost: usize,
-    node: ((isize, isize), Dir),
-}
-
-impl Ord for State {
-    fn cmp(&self, other: &Self) -> Ordering {
-        other
-            .cost
-            .cmp(&self.cost)
-            .then_with(|| self.node.cmp(&other.node))
-    }
-}
-
-impl PartialOrd for State {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
-    }
-}
+// where the reindeer is and which way it faces
+type Node = ((isize, isize), Dir);
 
-fn to_dist_index(grid: &Grid, pos_and_dir: &((isize, isize), Dir)) -> usize {
-    4 * grid.to_index(pos_and_dir.0) + pos_and_dir.1 as usize
-}
+// turning costs 1000, a step forward 1
+fn edges(maze: &Maze, &(pos, dir): &Node) -> Vec<(Node, usize)> {
+    let mut result = dir.next().map(|turn| ((pos, turn), 1000)).to_vec();
 
-fn edges(grid: &Grid, pos_and_dir: &((isize, isize), Dir)) -> Vec<State> {
-    let mut result = Vec::with_capacity(3);
-    let next_dirs = pos_and_dir.1.next();
-
-    result.extend([
-        State {
-            cost: 1000,
-            node: (pos_and_dir.0, next_dirs[0]),
-        },
-        State {
-            cost: 1000,
-            node: (pos_and_dir.0, next_dirs[1]),
-        },
-    ]);
-
-    if let Some(next) = grid.next(pos_and_dir.0, pos_and_dir.1) {
-        result.push(State {
-            cost: 1,
-            node: (next, pos_and_dir.1),
-        });
+    if let Some(next) = maze.next(pos, dir) {
+        result.push(((next, dir), 1));
     }
 
     result
 }
 
-fn dijkstra(
-    grid: &Grid,
-    start: ((isize, isize), Dir),
-    end: ((isize, isize), Dir),
-) -> Option<usize> {
-    let mut dist = vec![usize::MAX; 4 * grid.cells.len()];
-    let mut heap = BinaryHeap::new();
-
-    dist[to_dist_index(grid, &start)] = 0;
-    heap.push(State {
-        cost: 0,
-        node: start,
-    });
-
-    while let Some(State { cost, node }) = heap.pop() {
-        if node == end {
-            return Some(cost);
-        }
-
-        let node_index = to_dist_index(grid, &node);
-
-        if cost > dist[node_index] {
-            continue;
-        }
-
-        for edge in edges(grid, &node) {
-            let next = State {
-                cost: cost + edge.cost,
-                node: edge.node,
-            };
-
-            let next_index = to_dist_index(grid, &next.node);
-
-            if next.cost < dist[next_index] {
-                heap.push(next);
-                dist[next_index] = next.cost;
-            }
-        }
-    }
-
-    None
-}
-
 pub fn part1(input: &[u8]) -> usize {
-    let grid = Grid::from_bytes(input);
-
-    let start = (grid.start, Dir::Right);
-
-    [Dir::Left, Dir::Right, Dir::Up, Dir::Down]
-        .into_iter()
-        .filter_map(|dir| dijkstra(&grid, start, (grid.end, dir)))
-        .min()
-        .unwrap()
+    let maze = parse(input).unwrap();
+
+    search::dijkstra(
+        (maze.start, Dir::Right),
+        |node| edges(&maze, node),
+        |&(pos, _)| pos == maze.end,
+    )
+    .unwrap()
 }
 
-pub fn part2(input: &[u8]) -> usize {
-    let grid = input.split(|&b| b == b'\n').collect::<Vec<&[u8]>>();
+type JunctionGraph = (Vec<(isize, isize)>, Vec<usize>, Vec<Vec<(usize, usize)>>);
+
+fn junction_graph(grid: &[&[u8]]) -> JunctionGraph {
     let mut start = (0, 0);
     let mut end = (0, 0);
     let mut junctions = vec![];
@@ -371,15 +290,42 @@ pub fn part2(input: &[u8]) -> usize {
         }
     }
 
-    let start_ix = (junctions.len() - 2) * 4 + Dir::Right.as_int();
+    (junctions, d, adj)
+}
 
-    println!("{}", num_nodes);
+pub fn junctions(input: &[u8]) -> String {
+    let grid = input.split(|&b| b == b'\n').collect::<Vec<&[u8]>>();
+    let (junctions, d, adj) = junction_graph(&grid);
+    let num_nodes = junctions.len() * 4;
+
+    let mut lines = vec![];
+
+    for (i, edges) in adj.iter().enumerate() {
+        for &(j, cells) in edges.iter().filter(|(_, cells)| *cells > 0) {
+            lines.push(format!(
+                "{:?} {:?} -> {:?} {:?}: cost {}, {} cells",
+                junctions[i / 4],
+                Dir::from_int(i % 4),
+                junctions[j / 4],
+                Dir::from_int(j % 4),
+                d[i * num_nodes + j],
+                cells
+            ));
+        }
+    }
+
+    lines.join("\n")
+}
+
+pub fn part2(input: &[u8]) -> usize {
+    let grid = input.split(|&b| b == b'\n').collect::<Vec<&[u8]>>();
+    let (junctions, mut d, adj) = junction_graph(&grid);
+    let num_nodes = junctions.len() * 4;
+
+    let start_ix = (junctions.len() - 2) * 4 + Dir::Right.as_int();
 
     // floyd-warshall
     for k in 0..num_nodes {
-        if k % 10 == 0 {
-            println!("{}", k);
-        }
         for i in 0..num_nodes {
             for j in 0..num_nodes {
                 let ij = i * num_nodes + j;
@@ -448,16 +394,6 @@ pub fn part2(input: &[u8]) -> usize {
                 }
                 queue.push_back(n);
                 added.insert(to_add);
-                if l != 0 {
-                    println!(
-                        "from {:?}, {:?} to {:?}, {:?}: {}",
-                        junctions[node / 4],
-                        Dir::from_int(node % 4),
-                        junctions[n / 4],
-                        Dir::from_int(n % 4),
-                        l
-                    );
-                }
             }
         }
 
@@ -467,13 +403,6 @@ pub fn part2(input: &[u8]) -> usize {
     visited_cells
 }
 
-pub fn main() {
-    let input = INPUT.trim_ascii_end();
-
-    println!("{}", part1(input));
-    println!("{}", part2(input));
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
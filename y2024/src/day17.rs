@@ -0,0 +1,300 @@
+extern crate test;
+
+use aoc_core::cancel::{Cancel, Cancelled};
+use aoc_core::parse::{ParseError, integer};
+
+pub const INPUT: &[u8] = include_bytes!("../inputs/day17.txt");
+
+pub type Registers = (usize, usize, usize);
+
+// the registers and the program, a 3 bit opcode and operand per instruction; combo operand 7 is
+// rejected as the machine can't run it, and the program has to be a loop that outputs something
+// and shifts a right by a literal before jumping back to the start, so it always halts
+pub fn parse(input: &[u8]) -> Result<(Registers, Vec<u8>), ParseError> {
+    let mut lines = input.split(|&b| b == b'\n');
+    let mut register = |name| {
+        let value = lines
+            .next()
+            .and_then(|line| line.split(|&b| b == b' ').nth(2))
+            .ok_or(ParseError::Missing(name))?;
+        integer::<usize>(value)
+    };
+    let registers = (
+        register("register a")?,
+        register("register b")?,
+        register("register c")?,
+    );
+
+    let program = lines
+        .nth(1)
+        .and_then(|line| line.split(|&b| b == b' ').nth(1))
+        .ok_or(ParseError::Missing("program"))?
+        .split(|&b| b == b',')
+        .map(|bs| match integer::<u8>(bs)? {
+            n @ 0..=7 => Ok(n),
+            _ => Err(ParseError::Invalid("values have to fit in 3 bits")),
+        })
+        .collect::<Result<Vec<u8>, ParseError>>()?;
+
+    if program.len() % 2 != 0 {
+        return Err(ParseError::Missing("operand"));
+    }
+    let (body, end) = program.split_at(program.len().saturating_sub(2));
+    for instruction in body.chunks(2) {
+        match (instruction[0], instruction[1]) {
+            (0 | 2 | 5 | 6 | 7, 7) => {
+                return Err(ParseError::Invalid("combo operand 7 is reserved"));
+            }
+            (3, _) => {
+                return Err(ParseError::Invalid(
+                    "the only jump has to be the last instruction",
+                ));
+            }
+            _ => {}
+        }
+    }
+    if end != [3, 0] {
+        return Err(ParseError::Missing("jump back to the start"));
+    }
+    if !body.chunks(2).any(|instruction| instruction[0] == 5) {
+        return Err(ParseError::Missing("out instruction"));
+    }
+    if !body
+        .chunks(2)
+        .any(|instruction| instruction[0] == 0 && (1..=3).contains(&instruction[1]))
+    {
+        return Err(ParseError::Invalid("a has to shrink on every iteration"));
+    }
+
+    Ok((registers, program))
+}
+
+fn combo(operand: u8, a: usize, b: usize, c: usize) -> usize {
+    match operand {
+        0..=3 => operand as usize,
+        4 => a,
+        5 => b,
+        6 => c,
+        _ => panic!(),
+    }
+}
+
+fn literal(operand: u8) -> usize {
+    operand as usize
+}
+
+// registers can hold more than 64 bits worth of shift, which clears the value
+fn shift(value: usize, by: usize) -> usize {
+    u32::try_from(by)
+        .ok()
+        .and_then(|by| value.checked_shr(by))
+        .unwrap_or(0)
+}
+
+// runs the loop body up to its first output
+fn run_once(program: &[u8], a: usize) -> Option<usize> {
+    let mut a = a;
+    let mut b = 0;
+    let mut c = 0;
+
+    let mut ix = 0;
+
+    while ix < program.len() {
+        let op = program[ix];
+        let operand = program[ix + 1];
+
+        match op {
+            0 => a = shift(a, combo(operand, a, b, c)),
+            1 => {
+                b ^= literal(operand);
+            }
+            2 => {
+                b = combo(operand, a, b, c) & 0b111;
+            }
+            3 => return None,
+            4 => {
+                b ^= c;
+            }
+            5 => return Some(combo(operand, a, b, c) & 0b111),
+            6 => b = shift(a, combo(operand, a, b, c)),
+            7 => c = shift(a, combo(operand, a, b, c)),
+            _ => panic!(),
+        }
+
+        ix += 2;
+    }
+
+    None
+}
+
+fn next(a: usize, x: usize, program: &[u8]) -> Vec<usize> {
+    let a = a << 3;
+
+    (0..8)
+        .map(|i| a ^ i)
+        .filter(|&b| run_once(program, b) == Some(x))
+        .collect::<Vec<_>>()
+}
+
+fn dfs(
+    a: usize,
+    depth: usize,
+    program: &[u8],
+    cancel: &Cancel,
+) -> Result<Option<usize>, Cancelled> {
+    cancel.check()?;
+
+    if depth == program.len() {
+        return Ok(Some(a));
+    }
+
+    let target = program[program.len() - depth - 1];
+
+    for n in next(a, target as usize, program) {
+        if let Some(result) = dfs(n, depth + 1, program, cancel)? {
+            return Ok(Some(result));
+        }
+    }
+
+    Ok(None)
+}
+
+pub fn part1_with_cancel(input: &[u8], cancel: &Cancel) -> Result<String, Cancelled> {
+    let ((mut a, mut b, mut c), program) = parse(input).unwrap();
+
+    let mut ix = 0;
+    let mut out = vec![];
+
+    while ix < program.len() {
+        cancel.check()?;
+
+        let op = program[ix];
+        let operand = program[ix + 1];
+
+        match op {
+            0 => a = shift(a, combo(operand, a, b, c)),
+            1 => {
+                b ^= literal(operand);
+            }
+            2 => {
+                b = combo(operand, a, b, c) % 8;
+            }
+            3 => {
+                if a != 0 {
+                    ix = literal(operand);
+                    continue;
+                }
+            }
+            4 => {
+                b ^= c;
+            }
+            5 => {
+                out.push((combo(operand, a, b, c) % 8) as u8);
+            }
+            6 => b = shift(a, combo(operand, a, b, c)),
+            7 => c = shift(a, combo(operand, a, b, c)),
+            _ => panic!(),
+        }
+
+        ix += 2;
+    }
+
+    Ok(out
+        .iter()
+        .map(|o| o.to_string())
+        .collect::<Vec<_>>()
+        .join(","))
+}
+
+pub fn part1(input: &[u8]) -> String {
+    part1_with_cancel(input, &Cancel::default()).unwrap()
+}
+
+// none if no value of a makes the program output itself
+pub fn part2_with_cancel(input: &[u8], cancel: &Cancel) -> Result<Option<usize>, Cancelled> {
+    let (_, program) = parse(input).unwrap();
+
+    dfs(0, 0, &program, cancel)
+}
+
+pub fn part2(input: &[u8]) -> Option<usize> {
+    part2_with_cancel(input, &Cancel::default()).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test::Bencher;
+
+    const TEST_INPUT_1: &[u8] = include_bytes!("../test_inputs/day17_part1.txt");
+    const TEST_INPUT_2: &[u8] = include_bytes!("../test_inputs/day17_part2.txt");
+
+    #[test]
+    fn test_part1() {
+        let input = TEST_INPUT_1.trim_ascii_end();
+        assert_eq!(part1(input), "4,6,3,5,6,3,5,2,1,0");
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = TEST_INPUT_2.trim_ascii_end();
+        assert_eq!(part2(input), Some(117440));
+    }
+
+    #[test]
+    fn test_parse() {
+        let with_program = |program: &str| {
+            format!(
+                "Register A: 729\nRegister B: 0\nRegister C: 0\n\n{}",
+                program
+            )
+        };
+
+        assert_eq!(
+            parse(with_program("Program: 0,1,5,4,3,0").as_bytes()),
+            Ok(((729, 0, 0), vec![0, 1, 5, 4, 3, 0]))
+        );
+        assert_eq!(
+            parse(with_program("Program: 5,7,3,0").as_bytes()),
+            Err(ParseError::Invalid("combo operand 7 is reserved"))
+        );
+        assert_eq!(
+            parse(with_program("Program: 3,0,5,4,3,0").as_bytes()),
+            Err(ParseError::Invalid(
+                "the only jump has to be the last instruction"
+            ))
+        );
+        assert_eq!(
+            parse(with_program("Program: 0,1,5,4").as_bytes()),
+            Err(ParseError::Missing("jump back to the start"))
+        );
+        assert_eq!(
+            parse(with_program("Program: 1,0,3,0").as_bytes()),
+            Err(ParseError::Missing("out instruction"))
+        );
+        assert_eq!(
+            parse(with_program("Program: 0,4,5,4,3,0").as_bytes()),
+            Err(ParseError::Invalid("a has to shrink on every iteration"))
+        );
+        assert_eq!(
+            parse(with_program("Program: 0,1,5").as_bytes()),
+            Err(ParseError::Missing("operand"))
+        );
+        assert_eq!(
+            parse(b"Register A: 729\nRegister B: 0"),
+            Err(ParseError::Missing("register c"))
+        );
+    }
+
+    #[bench]
+    fn bench_part1(b: &mut Bencher) {
+        let input = INPUT.trim_ascii_end();
+        b.iter(|| part1(input))
+    }
+
+    #[bench]
+    fn bench_part2(b: &mut Bencher) {
+        let input = INPUT.trim_ascii_end();
+        b.iter(|| part2(input))
+    }
+}
@@ -0,0 +1,154 @@
+extern crate test;
+
+use aoc_core::grid::Grid;
+use aoc_core::parse::{ParseError, integer};
+use aoc_core::search;
+
+pub const INPUT: &[u8] = include_bytes!("../inputs/day18.txt");
+
+const GRID_SIZE: usize = 71;
+
+// col,row of each falling byte, all of them inside the grid and clear of the start and the exit
+pub fn parse_with_size(input: &[u8], grid_size: usize) -> Result<Vec<(usize, usize)>, ParseError> {
+    input
+        .split(|&b| b == b'\n')
+        .map(|line| {
+            let comma = line
+                .iter()
+                .position(|&b| b == b',')
+                .ok_or(ParseError::Missing("','"))?;
+            let col = integer::<usize>(&line[..comma])?;
+            let row = integer::<usize>(&line[comma + 1..])?;
+            if col >= grid_size || row >= grid_size {
+                return Err(ParseError::Invalid("byte falls outside the memory space"));
+            }
+            if (col, row) == (0, 0) || (col, row) == (grid_size - 1, grid_size - 1) {
+                return Err(ParseError::Invalid("byte falls on the start or the exit"));
+            }
+            Ok((col, row))
+        })
+        .collect()
+}
+
+pub fn parse(input: &[u8]) -> Result<Vec<(usize, usize)>, ParseError> {
+    parse_with_size(input, GRID_SIZE)
+}
+
+// how many bytes have fallen when each cell gets corrupted, usize::MAX for cells that never do
+fn corruption(bytes: &[(usize, usize)], grid_size: usize) -> Grid<usize> {
+    let mut corrupted = Grid {
+        rows: grid_size,
+        cols: grid_size,
+        cells: vec![usize::MAX; grid_size * grid_size],
+    };
+
+    for (num, &(col, row)) in bytes.iter().enumerate().rev() {
+        corrupted[(row, col)] = num;
+    }
+
+    corrupted
+}
+
+// steps from the top left to the bottom right corner once the first fallen bytes are down
+fn shortest_path(corrupted: &Grid<usize>, fallen: usize) -> Option<usize> {
+    let exit = (corrupted.rows - 1, corrupted.cols - 1);
+
+    search::bfs((0, 0), |&pos| {
+        corrupted
+            .neighbours(pos)
+            .filter(|&n| corrupted[n] >= fallen)
+            .collect::<Vec<_>>()
+    })
+    .get(&exit)
+    .copied()
+}
+
+// none if the fallen bytes cut the exit off
+pub fn part1_with_parameters(input: &[u8], grid_size: usize, steps: usize) -> Option<usize> {
+    let bytes = parse_with_size(input, grid_size).unwrap();
+    shortest_path(&corruption(&bytes, grid_size), steps)
+}
+
+pub fn part1(input: &[u8]) -> String {
+    match part1_with_parameters(input, GRID_SIZE, 1024) {
+        Some(steps) => steps.to_string(),
+        None => "no path".to_string(),
+    }
+}
+
+// the first byte that cuts the exit off, none if the exit stays reachable
+pub fn part2_with_parameters(input: &[u8], grid_size: usize) -> Option<(usize, usize)> {
+    let bytes = parse_with_size(input, grid_size).unwrap();
+    let corrupted = corruption(&bytes, grid_size);
+
+    if shortest_path(&corrupted, bytes.len()).is_some() {
+        return None;
+    }
+
+    // the exit is reachable with good bytes fallen but not with bad
+    let mut good = 0;
+    let mut bad = bytes.len();
+
+    while bad - good > 1 {
+        let mid = good + (bad - good) / 2;
+        match shortest_path(&corrupted, mid) {
+            Some(_) => good = mid,
+            _ => bad = mid,
+        };
+    }
+
+    Some(bytes[bad - 1])
+}
+
+pub fn part2(input: &[u8]) -> String {
+    match part2_with_parameters(input, GRID_SIZE) {
+        Some((col, row)) => format!("{},{}", col, row),
+        None => "no blocking byte".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test::Bencher;
+
+    const TEST_INPUT: &[u8] = include_bytes!("../test_inputs/day18.txt");
+
+    #[test]
+    fn test_part1() {
+        let input = TEST_INPUT.trim_ascii_end();
+        assert_eq!(part1_with_parameters(input, 7, 12), Some(22));
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(parse_with_size(b"1,0\n2,3", 7), Ok(vec![(1, 0), (2, 3)]));
+        assert_eq!(
+            parse_with_size(b"0,0", 7),
+            Err(ParseError::Invalid("byte falls on the start or the exit"))
+        );
+        assert_eq!(
+            parse_with_size(b"6,7", 7),
+            Err(ParseError::Invalid("byte falls outside the memory space"))
+        );
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = TEST_INPUT.trim_ascii_end();
+        assert_eq!(part2_with_parameters(input, 7), Some((6, 1)));
+        assert_eq!(part2_with_parameters(b"1,0", 7), None);
+    }
+
+    #[bench]
+    fn bench_part1(b: &mut Bencher) {
+        let input = INPUT.trim_ascii_end();
+        b.iter(|| part1(input))
+    }
+
+    #[bench]
+    fn bench_part2(b: &mut Bencher) {
+        let input = INPUT.trim_ascii_end();
+        b.iter(|| part2(input))
+    }
+}
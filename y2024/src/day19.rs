@@ -3,9 +3,42 @@ extern crate test;
 use std::collections::HashMap;
 use std::sync::Mutex;
 
+use aoc_core::parse::ParseError;
 use memoize::lazy_static::lazy_static;
 
-const INPUT: &[u8] = include_bytes!("../inputs/day19.txt");
+pub const INPUT: &[u8] = include_bytes!("../inputs/day19.txt");
+
+// an empty towel would match forever, so every towel and design needs at least one stripe
+fn stripes(bytes: &[u8]) -> Result<&[u8], ParseError> {
+    match bytes.iter().find(|b| !b.is_ascii_lowercase()) {
+        Some(&b) => Err(ParseError::InvalidByte(b)),
+        None if bytes.is_empty() => Err(ParseError::Empty),
+        None => Ok(bytes),
+    }
+}
+
+// the towels and the designs
+type Lists<'a> = (Vec<&'a [u8]>, Vec<&'a [u8]>);
+
+// the towels separated by ", ", an empty line, then one design per line
+pub fn parse(input: &[u8]) -> Result<Lists<'_>, ParseError> {
+    let mut lines = input.split(|&b| b == b'\n');
+
+    let towels = lines
+        .next()
+        .ok_or(ParseError::Empty)?
+        .split(|&b| b == b',')
+        .map(|bs| stripes(bs.strip_prefix(b" ").unwrap_or(bs)))
+        .collect::<Result<_, _>>()?;
+
+    if !lines.next().is_some_and(|line| line.is_empty()) {
+        return Err(ParseError::Missing("empty line"));
+    }
+
+    let designs = lines.map(stripes).collect::<Result<_, _>>()?;
+
+    Ok((towels, designs))
+}
 
 fn is_subslice<T: PartialEq>(haystack: &[T], needle: &[T]) -> bool {
     if haystack.len() < needle.len() {
@@ -46,20 +79,11 @@ fn can_build(towel: &[u8], towels: &[&[u8]]) -> bool {
 }
 
 pub fn part1(input: &[u8]) -> usize {
-    let mut lines = input.split(|&b| b == b'\n');
-
-    let towels = lines
-        .next()
-        .unwrap()
-        .split(|&b| b == b',')
-        .map(|bs| if bs[0] == b' ' { &bs[1..] } else { bs })
-        .collect::<Vec<&[u8]>>();
-
-    lines.next();
+    let (towels, designs) = parse(input).unwrap();
 
     let mut result = 0;
-    for towel in lines {
-        if can_build(towel, &towels) {
+    for design in designs {
+        if can_build(design, &towels) {
             result += 1;
         }
     }
@@ -90,32 +114,16 @@ fn can_build_how_many(towel: &[u8], towels: &[&[u8]]) -> usize {
 }
 
 pub fn part2(input: &[u8]) -> usize {
-    let mut lines = input.split(|&b| b == b'\n');
-
-    let towels = lines
-        .next()
-        .unwrap()
-        .split(|&b| b == b',')
-        .map(|bs| if bs[0] == b' ' { &bs[1..] } else { bs })
-        .collect::<Vec<&[u8]>>();
-
-    lines.next();
+    let (towels, designs) = parse(input).unwrap();
 
     let mut result = 0;
-    for towel in lines {
-        result += can_build_how_many(towel, &towels)
+    for design in designs {
+        result += can_build_how_many(design, &towels)
     }
 
     result
 }
 
-pub fn main() {
-    let input = INPUT.trim_ascii_end();
-
-    println!("{}", part1(input));
-    println!("{}", part2(input));
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
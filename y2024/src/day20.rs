@@ -2,7 +2,10 @@ extern crate test;
 
 use std::collections::HashMap;
 
-const INPUT: &[u8] = include_bytes!("../inputs/day20.txt");
+use aoc_core::grid::Grid;
+use aoc_core::parse::ParseError;
+
+pub const INPUT: &[u8] = include_bytes!("../inputs/day20.txt");
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Cell {
@@ -11,98 +14,77 @@ enum Cell {
 }
 
 #[derive(Debug)]
-struct Grid {
-    cols: usize,
-    rows: usize,
-    cells: Vec<Cell>,
+struct Track {
+    grid: Grid<Cell>,
     start: (isize, isize),
     end: (isize, isize),
 }
 
-impl Grid {
-    fn from_bytes(bytes: &[u8]) -> Self {
-        let mut cells = vec![];
-        let mut start = (0, 0);
-        let mut end = (0, 0);
-
-        for (row, line) in bytes.split(|&b| b == b'\n').enumerate() {
-            for (col, b) in line.iter().enumerate() {
-                match b {
-                    b'#' => cells.push(Cell::Wall),
-                    b'.' => cells.push(Cell::Empty),
-                    b'S' => {
-                        cells.push(Cell::Empty);
-                        start = (row as isize, col as isize)
-                    }
-                    b'E' => {
-                        cells.push(Cell::Empty);
-                        end = (row as isize, col as isize)
-                    }
-                    _ => panic!("invalid byte: {}", *b as char),
-                }
-            }
-        }
-
-        let cols = bytes.iter().take_while(|&&b| b != b'\n').count();
-        let rows = cells.len() / cols;
-
-        Self {
-            cols,
-            rows,
-            cells,
-            start,
-            end,
-        }
-    }
-
-    fn is_empty(&self, pos: &(isize, isize)) -> bool {
-        matches!(self.cells[self.to_index(pos)], Cell::Empty)
+impl Track {
+    fn from_bytes(bytes: &[u8]) -> Result<Self, ParseError> {
+        let plan = Grid::parse(bytes, |b| match b {
+            b'#' | b'.' | b'S' | b'E' => Ok(b),
+            _ => Err(ParseError::InvalidByte(b)),
+        })?;
+        let find = |c, what| {
+            let (row, col) = plan
+                .position(|&b| b == c)
+                .ok_or(ParseError::Missing(what))?;
+            Ok((row as isize, col as isize))
+        };
+        let start = find(b'S', "start")?;
+        let end = find(b'E', "end")?;
+        let grid = plan.map(|&b| match b {
+            b'#' => Cell::Wall,
+            _ => Cell::Empty,
+        });
+
+        Ok(Self { grid, start, end })
     }
 
     fn set_wall(&mut self, pos: &(isize, isize)) {
-        let ix = self.to_index(pos);
-        self.cells[ix] = Cell::Wall;
-    }
-
-    fn to_index(&self, pos: &(isize, isize)) -> usize {
-        pos.0 as usize * self.cols + pos.1 as usize
+        self.grid[(pos.0 as usize, pos.1 as usize)] = Cell::Wall;
     }
 
-    fn trace_path(&mut self) -> Vec<(isize, isize)> {
+    // none unless the track is a single path without branches from start to end
+    fn trace_path(&mut self) -> Option<Vec<(isize, isize)>> {
         let mut path = vec![];
         let mut pos = self.start;
 
         loop {
             path.push(pos);
             if pos == self.end {
-                return path;
+                return Some(path);
             }
             let next_positions = self.next(pos);
-            assert_eq!(next_positions.len(), 1);
+            if next_positions.len() != 1 {
+                return None;
+            }
             self.set_wall(&pos);
             pos = next_positions[0];
         }
     }
 
     fn next(&self, pos: (isize, isize)) -> Vec<(isize, isize)> {
-        [
-            (pos.0 - 1, pos.1),
-            (pos.0 + 1, pos.1),
-            (pos.0, pos.1 - 1),
-            (pos.0, pos.1 + 1),
-        ]
-        .into_iter()
-        .filter(|&(row, col)| {
-            row >= 0 && row < self.rows as isize && col >= 0 && col < self.cols as isize
-        })
-        .filter(|p| self.is_empty(p))
-        .collect::<Vec<(isize, isize)>>()
+        self.grid
+            .neighbours((pos.0 as usize, pos.1 as usize))
+            .filter(|&n| self.grid[n] == Cell::Empty)
+            .map(|(row, col)| (row as isize, col as isize))
+            .collect()
     }
 }
 
-fn solve(input: &[u8], cheat_length: usize) -> HashMap<usize, usize> {
-    let mut grid = Grid::from_bytes(input);
-    let path = grid.trace_path();
+// the cells of the race track from start to end
+pub fn parse(input: &[u8]) -> Result<Vec<(isize, isize)>, ParseError> {
+    Track::from_bytes(input)?
+        .trace_path()
+        .ok_or(ParseError::Invalid(
+            "the track isn't a single path from start to end",
+        ))
+}
+
+pub fn solve(input: &[u8], cheat_length: usize) -> HashMap<usize, usize> {
+    let path = parse(input).unwrap();
     let distance_from_start: HashMap<(isize, isize), usize> = path
         .iter()
         .cloned()
@@ -156,13 +138,6 @@ pub fn part2(input: &[u8]) -> usize {
         .sum()
 }
 
-pub fn main() {
-    let input = INPUT.trim_ascii_end();
-
-    println!("{}", part1(input));
-    println!("{}", part2(input));
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -175,19 +150,22 @@ mod tests {
         let input = TEST_INPUT.trim_ascii_end();
         let mut result = solve(input, 2).into_iter().collect::<Vec<_>>();
         result.sort();
-        assert_eq!(result, [
-            (2, 14),
-            (4, 14),
-            (6, 2),
-            (8, 4),
-            (10, 2),
-            (12, 3),
-            (20, 1),
-            (36, 1),
-            (38, 1),
-            (40, 1),
-            (64, 1)
-        ]);
+        assert_eq!(
+            result,
+            [
+                (2, 14),
+                (4, 14),
+                (6, 2),
+                (8, 4),
+                (10, 2),
+                (12, 3),
+                (20, 1),
+                (36, 1),
+                (38, 1),
+                (40, 1),
+                (64, 1)
+            ]
+        );
     }
 
     #[test]
@@ -198,22 +176,25 @@ mod tests {
             .filter(|&(k, _)| k >= 50)
             .collect::<Vec<_>>();
         result.sort();
-        assert_eq!(result, [
-            (50, 32),
-            (52, 31),
-            (54, 29),
-            (56, 39),
-            (58, 25),
-            (60, 23),
-            (62, 20),
-            (64, 19),
-            (66, 12),
-            (68, 14),
-            (70, 12),
-            (72, 22),
-            (74, 4),
-            (76, 3)
-        ]);
+        assert_eq!(
+            result,
+            [
+                (50, 32),
+                (52, 31),
+                (54, 29),
+                (56, 39),
+                (58, 25),
+                (60, 23),
+                (62, 20),
+                (64, 19),
+                (66, 12),
+                (68, 14),
+                (70, 12),
+                (72, 22),
+                (74, 4),
+                (76, 3)
+            ]
+        );
     }
 
     #[bench]
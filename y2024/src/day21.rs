@@ -1,9 +1,10 @@
 extern crate test;
 
+use aoc_core::parse::ParseError;
 use memoize::memoize;
 use std::iter::once;
 
-const INPUT: &[u8] = include_bytes!("../inputs/day21.txt");
+pub const INPUT: &[u8] = include_bytes!("../inputs/day21.txt");
 
 #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
 enum Dir {
@@ -15,7 +16,7 @@ enum Dir {
 }
 
 #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
-enum Num {
+pub enum Num {
     Zero,
     One,
     Two,
@@ -179,14 +180,29 @@ impl Num {
     }
 }
 
-pub fn part1(input: &[u8]) -> usize {
+// door codes, three digits followed by A
+pub fn parse(input: &[u8]) -> Result<Vec<Vec<Num>>, ParseError> {
     input
         .split(|&b| b == b'\n')
-        .map(|line| {
-            let digits = line
+        .map(|line| match line {
+            [digits @ .., b'A'] if digits.len() == 3 => digits
                 .iter()
-                .map(|&b| Num::from_byte(b))
-                .collect::<Vec<Num>>();
+                .map(|&b| match b {
+                    b'0'..=b'9' => Ok(Num::from_byte(b)),
+                    _ => Err(ParseError::InvalidByte(b)),
+                })
+                .chain(once(Ok(Num::A)))
+                .collect(),
+            _ => Err(ParseError::Invalid("codes are three digits followed by A")),
+        })
+        .collect()
+}
+
+pub fn part1(input: &[u8]) -> usize {
+    parse(input)
+        .unwrap()
+        .into_iter()
+        .map(|digits| {
             let mut prev = Num::A;
             let mut min_steps = 0;
             for digit in digits.iter() {
@@ -203,13 +219,10 @@ pub fn part1(input: &[u8]) -> usize {
 }
 
 pub fn part2(input: &[u8]) -> usize {
-    input
-        .split(|&b| b == b'\n')
-        .map(|line| {
-            let digits = line
-                .iter()
-                .map(|&b| Num::from_byte(b))
-                .collect::<Vec<Num>>();
+    parse(input)
+        .unwrap()
+        .into_iter()
+        .map(|digits| {
             let mut prev = Num::A;
             let mut min_steps = 0;
             for digit in digits.iter() {
@@ -225,13 +238,6 @@ pub fn part2(input: &[u8]) -> usize {
         .sum()
 }
 
-pub fn main() {
-    let input = INPUT.trim_ascii_end();
-
-    println!("{}", part1(input));
-    println!("{}", part2(input));
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
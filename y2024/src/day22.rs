@@ -1,22 +1,13 @@
 extern crate test;
 
+use aoc_core::parse::{ParseError, integer};
 use rayon::prelude::*;
 use std::collections::HashSet;
 
-const INPUT: &[u8] = include_bytes!("../inputs/day22.txt");
-
-const POWERS_OF_TEN: [usize; 10] = [
-    1, 10, 100, 1000, 10000, 100000, 1000000, 10000000, 100000000, 1000000000,
-];
+pub const INPUT: &[u8] = include_bytes!("../inputs/day22.txt");
 
 const MASK: usize = (1 << 24) - 1;
 
-fn usize_from_bytes(bytes: &[u8]) -> usize {
-    bytes.iter().rev().enumerate().fold(0, |acc, (ix, x)| {
-        acc + (x & 0x0f) as usize * POWERS_OF_TEN[ix]
-    })
-}
-
 fn next(mut x: usize) -> usize {
     x = ((x << 6) ^ x) & MASK;
     x = ((x >> 5) ^ x) & MASK;
@@ -25,18 +16,23 @@ fn next(mut x: usize) -> usize {
     x
 }
 
+// one initial secret number per buyer
+pub fn parse(input: &[u8]) -> Result<Vec<usize>, ParseError> {
+    input.split(|&b| b == b'\n').map(integer::<usize>).collect()
+}
+
 pub fn part1(input: &[u8]) -> usize {
-    input
-        .split(|&b| b == b'\n')
-        .map(usize_from_bytes)
+    parse(input)
+        .unwrap()
+        .into_iter()
         .map(|x| (0..2000).fold(x, |acc, _| next(acc)))
         .sum()
 }
 
 pub fn part2(input: &[u8]) -> usize {
-    let prices = input
-        .split(|&b| b == b'\n')
-        .map(usize_from_bytes)
+    let prices = parse(input)
+        .unwrap()
+        .into_iter()
         .map(|x0| {
             let mut v = Vec::with_capacity(2000);
             let mut x = x0;
@@ -77,13 +73,6 @@ pub fn part2(input: &[u8]) -> usize {
         .unwrap()
 }
 
-pub fn main() {
-    let input = INPUT.trim_ascii_end();
-
-    println!("{}", part1(input));
-    println!("{}", part2(input));
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
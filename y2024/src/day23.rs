@@ -3,7 +3,9 @@ extern crate test;
 use std::collections::HashSet;
 use std::iter::once;
 
-const INPUT: &[u8] = include_bytes!("../inputs/day23.txt");
+use aoc_core::parse::ParseError;
+
+pub const INPUT: &[u8] = include_bytes!("../inputs/day23.txt");
 
 const DIM: usize = 26 * 26;
 
@@ -15,32 +17,57 @@ fn from_int(i: usize) -> (u8, u8) {
     (b'a' + (i / 26) as u8, b'a' + (i % 26) as u8)
 }
 
-pub fn part1(input: &[u8]) -> usize {
-    let mut adj = vec![vec![]; DIM];
+fn node_name(i: usize) -> String {
+    let (a, b) = from_int(i);
+    format!("{}{}", a as char, b as char)
+}
 
-    let mut start_nodes = HashSet::new();
+// one connection per line, two lowercase names joined by '-'
+pub fn parse(input: &[u8]) -> Result<Vec<Vec<usize>>, ParseError> {
+    let mut adj = vec![vec![]; DIM];
 
     for line in input.split(|&b| b == b'\n') {
-        let node_1_a = line[0];
-        let node_1_b = line[1];
-
-        let node_2_a = line[3];
-        let node_2_b = line[4];
+        let &[a1, a2, b'-', b1, b2] = line else {
+            return Err(ParseError::Invalid("connections look like ab-cd"));
+        };
+        if let Some(&b) = [a1, a2, b1, b2].iter().find(|b| !b.is_ascii_lowercase()) {
+            return Err(ParseError::InvalidByte(b));
+        }
 
-        let node_1 = to_int(node_1_a, node_1_b);
-        let node_2 = to_int(node_2_a, node_2_b);
+        let node_1 = to_int(a1, a2);
+        let node_2 = to_int(b1, b2);
 
         adj[node_1].push(node_2);
         adj[node_2].push(node_1);
+    }
 
-        if node_1_a == b't' {
-            start_nodes.insert(node_1);
-        }
+    Ok(adj)
+}
 
-        if node_2_a == b't' {
-            start_nodes.insert(node_2);
-        }
-    }
+pub fn adjacency(input: &[u8]) -> String {
+    parse(input)
+        .unwrap()
+        .iter()
+        .enumerate()
+        .filter(|(_, neighbours)| !neighbours.is_empty())
+        .map(|(node, neighbours)| {
+            let mut names = neighbours
+                .iter()
+                .map(|&n| node_name(n))
+                .collect::<Vec<String>>();
+            names.sort();
+            format!("{}: {}", node_name(node), names.join(","))
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+pub fn part1(input: &[u8]) -> usize {
+    let adj = parse(input).unwrap();
+
+    let start_nodes = (0..DIM)
+        .filter(|&n| !adj[n].is_empty() && from_int(n).0 == b't')
+        .collect::<Vec<usize>>();
 
     let cycles_1 = start_nodes
         .iter()
@@ -120,28 +147,9 @@ fn bron_kerbosch(
 }
 
 pub fn part2(input: &[u8]) -> String {
-    let mut adj = vec![vec![]; DIM];
+    let adj = parse(input).unwrap();
 
-    let mut nodes = HashSet::new();
-
-    for line in input.split(|&b| b == b'\n') {
-        let node_1_a = line[0];
-        let node_1_b = line[1];
-
-        let node_2_a = line[3];
-        let node_2_b = line[4];
-
-        let node_1 = to_int(node_1_a, node_1_b);
-        let node_2 = to_int(node_2_a, node_2_b);
-
-        adj[node_1].push(node_2);
-        adj[node_2].push(node_1);
-
-        nodes.insert(node_1);
-        nodes.insert(node_2);
-    }
-
-    let p = nodes;
+    let p = (0..DIM).filter(|&n| !adj[n].is_empty()).collect();
     let r = HashSet::new();
     let x = HashSet::new();
     let mut cliques = Vec::new();
@@ -152,22 +160,12 @@ pub fn part2(input: &[u8]) -> String {
 
     let mut result = max_component_members
         .iter()
-        .map(|n| {
-            let (a, b) = from_int(*n);
-            format!("{}{}", a as char, b as char)
-        })
+        .map(|&n| node_name(n))
         .collect::<Vec<String>>();
     result.sort();
     result.join(",")
 }
 
-pub fn main() {
-    let input = INPUT.trim_ascii_end();
-
-    println!("{}", part1(input));
-    println!("{}", part2(input));
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,291 @@
+extern crate test;
+
+use std::collections::HashMap;
+use std::collections::VecDeque;
+
+use aoc_core::parse::ParseError;
+
+pub const INPUT: &[u8] = include_bytes!("../inputs/day24.txt");
+
+type Gate = [u8; 3];
+
+type Gates = HashMap<Gate, (Op, Gate, Gate)>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Op {
+    And,
+    Or,
+    Xor,
+}
+
+impl Op {
+    fn name(&self) -> &'static str {
+        match self {
+            Op::And => "AND",
+            Op::Or => "OR",
+            Op::Xor => "XOR",
+        }
+    }
+
+    fn apply(&self, in_1: bool, in_2: bool) -> bool {
+        match self {
+            Op::And => in_1 & in_2,
+            Op::Or => in_1 | in_2,
+            Op::Xor => in_1 ^ in_2,
+        }
+    }
+}
+
+fn get_output(values: &HashMap<Gate, bool>, len: usize) -> usize {
+    let mut output = 0;
+
+    for i in (0..len).rev() {
+        let d1 = ((i / 10) as u8) + b'0';
+        let d2 = ((i % 10) as u8) + b'0';
+        let value = values.get(&[b'z', d1, d2]).unwrap();
+        output <<= 1;
+        output += *value as usize;
+    }
+
+    output
+}
+
+fn run(gates: &Gates, values: &mut HashMap<Gate, bool>, len: usize) {
+    let mut queue = (0..len)
+        .map(|i| {
+            let d1 = ((i / 10) as u8) + b'0';
+            let d2 = ((i % 10) as u8) + b'0';
+            [b'z', d1, d2]
+        })
+        .collect::<VecDeque<_>>();
+
+    while let Some(gate) = queue.pop_front() {
+        let (op, in_1, in_2) = gates.get(&gate).unwrap();
+        let val_1 = values.get(in_1);
+        let val_2 = values.get(in_2);
+
+        if val_1.is_none() {
+            queue.push_back(*in_1);
+        }
+
+        if val_2.is_none() {
+            queue.push_back(*in_2);
+        }
+
+        match val_1.is_some() && val_2.is_some() {
+            true => {
+                values.insert(gate, op.apply(*val_1.unwrap(), *val_2.unwrap()));
+            }
+            false => {
+                queue.push_back(gate);
+            }
+        }
+    }
+}
+
+fn wire(bytes: &[u8]) -> Result<Gate, ParseError> {
+    let &[a, b, c] = bytes else {
+        return Err(ParseError::Invalid("wire names are three characters"));
+    };
+    match [a, b, c].into_iter().find(|x| !x.is_ascii_alphanumeric()) {
+        Some(x) => Err(ParseError::InvalidByte(x)),
+        None => Ok([a, b, c]),
+    }
+}
+
+fn parse_gate(line: &[u8]) -> Result<(Gate, (Op, Gate, Gate)), ParseError> {
+    let parts = line.split(|&b| b == b' ').collect::<Vec<&[u8]>>();
+    let &[in_1, op, in_2, b"->", out] = parts.as_slice() else {
+        return Err(ParseError::Invalid("gates look like abc AND def -> ghi"));
+    };
+    let op = match op {
+        b"AND" => Op::And,
+        b"OR" => Op::Or,
+        b"XOR" => Op::Xor,
+        _ => return Err(ParseError::Invalid("unknown operation")),
+    };
+
+    Ok((wire(out)?, (op, wire(in_1)?, wire(in_2)?)))
+}
+
+// whether the wire ever gets a value, which it doesn't if it's never set or sits on a loop
+fn settles(
+    wire: Gate,
+    values: &HashMap<Gate, bool>,
+    gates: &Gates,
+    settled: &mut HashMap<Gate, bool>,
+) -> bool {
+    if values.contains_key(&wire) {
+        return true;
+    }
+    if let Some(&result) = settled.get(&wire) {
+        return result;
+    }
+    let Some(&(_, in_1, in_2)) = gates.get(&wire) else {
+        return false;
+    };
+
+    settled.insert(wire, false);
+    let result = settles(in_1, values, gates, settled) && settles(in_2, values, gates, settled);
+    settled.insert(wire, result);
+    result
+}
+
+// the initial values, an empty line and the gates; the z outputs have to be numbered from z00
+// without gaps and each of them has to settle
+pub fn parse(input: &[u8]) -> Result<(HashMap<Gate, bool>, Gates), ParseError> {
+    let mut lines = input.split(|&b| b == b'\n');
+    let mut values = HashMap::new();
+
+    for line in lines.by_ref() {
+        if line.is_empty() {
+            break;
+        }
+
+        let value = match line {
+            [name @ .., b':', b' ', b'0'] => (wire(name)?, false),
+            [name @ .., b':', b' ', b'1'] => (wire(name)?, true),
+            _ => return Err(ParseError::Invalid("initial values look like abc: 1")),
+        };
+        values.insert(value.0, value.1);
+    }
+
+    let gates = lines
+        .map(parse_gate)
+        .collect::<Result<Gates, ParseError>>()?;
+
+    let outputs = gates.keys().filter(|out| out[0] == b'z').count();
+    let mut settled = HashMap::new();
+    for i in 0..outputs {
+        let z = [b'z', (i / 10) as u8 + b'0', (i % 10) as u8 + b'0'];
+        if !gates.contains_key(&z) {
+            return Err(ParseError::Invalid("z outputs aren't numbered from z00"));
+        }
+        if !settles(z, &values, &gates, &mut settled) {
+            return Err(ParseError::Invalid("an output never gets a value"));
+        }
+    }
+
+    Ok((values, gates))
+}
+
+pub fn gates(input: &[u8]) -> String {
+    let (_, gates) = parse(input).unwrap();
+
+    let mut outputs = gates.keys().collect::<Vec<&Gate>>();
+    outputs.sort();
+
+    outputs
+        .into_iter()
+        .map(|out| {
+            let (op, in_1, in_2) = &gates[out];
+            format!(
+                "{} = {} {} {}",
+                String::from_utf8_lossy(out),
+                String::from_utf8_lossy(in_1),
+                op.name(),
+                String::from_utf8_lossy(in_2)
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+pub fn part1(input: &[u8]) -> usize {
+    let (mut values, gates) = parse(input).unwrap();
+    let outputs = gates.keys().filter(|out| out[0] == b'z').count();
+
+    run(&gates, &mut values, outputs);
+
+    get_output(&values, outputs)
+}
+
+// the wires that don't fit a ripple carry adder, where each bit's sum is x XOR y XOR carry and its
+// carry out is (x AND y) OR (carry AND (x XOR y)), with a half adder for the lowest bit and the
+// last carry going straight to the highest z
+pub fn part2(input: &[u8]) -> String {
+    let (_, gates) = parse(input).unwrap();
+
+    let outputs = gates.keys().filter(|out| out[0] == b'z').count();
+    let last = format!("z{:02}", outputs.saturating_sub(1));
+
+    let is_input = |wire: &Gate| wire[0] == b'x' || wire[0] == b'y';
+    let is_lowest = |wire: &Gate| is_input(wire) && &wire[1..] == b"00";
+    let feeds = |wire: &Gate, op: Op| {
+        gates
+            .values()
+            .any(|(o, in_1, in_2)| *o == op && (in_1 == wire || in_2 == wire))
+    };
+
+    let mut wrong = gates
+        .iter()
+        .filter(|(out, (op, in_1, in_2))| {
+            let from_inputs = is_input(in_1) && is_input(in_2);
+            match op {
+                _ if out[0] == b'z' && out[..] != *last.as_bytes() && *op != Op::Xor => true,
+                Op::Xor if !from_inputs => out[0] != b'z',
+                Op::Xor if !is_lowest(in_1) => !feeds(out, Op::Xor),
+                Op::And if !is_lowest(in_1) => !feeds(out, Op::Or),
+                _ => false,
+            }
+        })
+        .map(|(out, _)| String::from_utf8_lossy(out).into_owned())
+        .collect::<Vec<String>>();
+    wrong.sort();
+
+    wrong.join(",")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test::Bencher;
+
+    const TEST_INPUT: &[u8] = include_bytes!("../test_inputs/day24.txt");
+    const TEST_INPUT_2: &[u8] = include_bytes!("../test_inputs/day24_part2.txt");
+
+    #[test]
+    fn test_part1() {
+        let input = TEST_INPUT.trim_ascii_end();
+        assert_eq!(part1(input), 2024);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = TEST_INPUT_2.trim_ascii_end();
+        assert_eq!(part2(input), "mjd,ndq,vgg,z02");
+    }
+
+    #[test]
+    fn test_parse() {
+        assert!(parse(b"x00: 1\ny00: 0\n\nx00 XOR y00 -> z00").is_ok());
+        assert_eq!(
+            parse(b"x00: 1\n\nx00 NOT y00 -> z00").err(),
+            Some(ParseError::Invalid("unknown operation"))
+        );
+        assert_eq!(
+            parse(b"x00: 1\n\nx00 OR abc -> z00\nz00 AND x00 -> abc").err(),
+            Some(ParseError::Invalid("an output never gets a value"))
+        );
+        assert_eq!(
+            parse(b"x00: 1\n\nx00 OR y00 -> z00").err(),
+            Some(ParseError::Invalid("an output never gets a value"))
+        );
+        assert_eq!(
+            parse(b"x00: 1\n\nx00 OR x00 -> z01").err(),
+            Some(ParseError::Invalid("z outputs aren't numbered from z00"))
+        );
+    }
+
+    #[bench]
+    fn bench_part1(b: &mut Bencher) {
+        let input = INPUT.trim_ascii_end();
+        b.iter(|| part1(input))
+    }
+
+    #[bench]
+    fn bench_part2(b: &mut Bencher) {
+        let input = INPUT.trim_ascii_end();
+        b.iter(|| part2(input))
+    }
+}
@@ -0,0 +1,152 @@
+extern crate test;
+
+use aoc_core::parse::ParseError;
+use itertools::Itertools;
+
+pub const INPUT: &[u8] = include_bytes!("../inputs/day25.txt");
+
+fn parse_lock(bytes: &[u8]) -> [u8; 5] {
+    let mut heights = [0; 5];
+
+    for line in bytes.split(|&b| b == b'\n').skip(1).take(6) {
+        for (i, b) in line.iter().enumerate() {
+            if *b == b'#' {
+                heights[i] += 1;
+            }
+        }
+    }
+
+    heights
+}
+
+fn parse_key(bytes: &[u8]) -> [u8; 5] {
+    let mut heights = [5; 5];
+
+    for line in bytes.split(|&b| b == b'\n').skip(1).take(6) {
+        for (i, b) in line.iter().enumerate() {
+            if *b == b'.' {
+                heights[i] -= 1;
+            }
+        }
+    }
+
+    heights
+}
+
+type Heights = [u8; 5];
+
+// schematics are 7 rows of 5 pins with an empty line between them, keys are open at the top and
+// locks at the bottom
+pub fn parse(input: &[u8]) -> Result<(Vec<Heights>, Vec<Heights>), ParseError> {
+    let input = input.trim_ascii_end();
+    if input.is_empty() {
+        return Err(ParseError::Empty);
+    }
+
+    let lines = input.split(|&b| b == b'\n').collect::<Vec<_>>();
+    for schematic in lines.chunks(8) {
+        if schematic.len() < 7 {
+            return Err(ParseError::Missing("row"));
+        }
+        if schematic.len() == 8 && !schematic[7].is_empty() {
+            return Err(ParseError::Missing("empty line"));
+        }
+
+        for row in &schematic[..7] {
+            if row.len() != 5 {
+                return Err(ParseError::Invalid("rows are 5 pins wide"));
+            }
+            if let Some(&b) = row.iter().find(|&&b| b != b'#' && b != b'.') {
+                return Err(ParseError::InvalidByte(b));
+            }
+        }
+
+        match (schematic[0], schematic[6]) {
+            (b".....", b"#####") | (b"#####", b".....") => (),
+            _ => return Err(ParseError::Invalid("neither a key nor a lock")),
+        }
+    }
+
+    // every schematic now takes up exactly 6 rows of 5 pins and a newline plus the empty line
+    let mut keys = Vec::new();
+    let mut locks = Vec::new();
+
+    for chunk in input.chunks(6 * 7 + 1) {
+        match chunk[0] {
+            b'.' => keys.push(parse_key(chunk)),
+            _ => locks.push(parse_lock(chunk)),
+        }
+    }
+
+    Ok((keys, locks))
+}
+
+pub fn part1(input: &[u8]) -> usize {
+    let (mut keys, mut locks) = parse(input).unwrap();
+
+    keys.sort();
+    keys.dedup();
+
+    locks.sort();
+    locks.dedup();
+
+    keys.iter()
+        .cartesian_product(locks.iter())
+        .filter(|(key, lock)| key.iter().zip(lock.iter()).all(|(k, l)| k + l <= 5))
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test::Bencher;
+
+    const TEST_INPUT: &[u8] = include_bytes!("../test_inputs/day25.txt");
+
+    #[test]
+    fn test_parse_lock() {
+        assert_eq!(
+            parse_lock(b"#####\n.####\n.####\n.####\n.#.#.\n.#...\n.....\n\n"),
+            [0, 5, 3, 4, 3]
+        );
+    }
+
+    #[test]
+    fn test_parse_key() {
+        assert_eq!(
+            parse_key(b".....\n#....\n#....\n#...#\n#.#.#\n#.###\n####"),
+            [5, 0, 2, 1, 3]
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        let key = ".....\n#....\n#....\n#...#\n#.#.#\n#.###\n#####";
+        let lock = "#####\n.####\n.####\n.####\n.#.#.\n.#...\n.....";
+        assert_eq!(
+            parse(format!("{key}\n\n{lock}\n").as_bytes()),
+            Ok((vec![[5, 0, 2, 1, 3]], vec![[0, 5, 3, 4, 3]]))
+        );
+        assert_eq!(parse(b""), Err(ParseError::Empty));
+        assert_eq!(
+            parse(format!("{key}\n{lock}").as_bytes()),
+            Err(ParseError::Missing("empty line"))
+        );
+        assert_eq!(
+            parse(b".....\n.....\n.....\n.....\n.....\n.....\n....."),
+            Err(ParseError::Invalid("neither a key nor a lock"))
+        );
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = TEST_INPUT.trim_ascii_end();
+        assert_eq!(part1(input), 3);
+    }
+
+    #[bench]
+    fn bench_part1(b: &mut Bencher) {
+        let input = INPUT.trim_ascii_end();
+        b.iter(|| part1(input))
+    }
+}
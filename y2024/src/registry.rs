@@ -0,0 +1,476 @@
+use std::str::FromStr;
+
+use aoc_core::cancel::Cancel;
+use aoc_core::grid::Grid;
+use aoc_core::registry::{Command, Day, str_from_bytes};
+use aoc_core::{parse, parts};
+
+use crate::*;
+
+pub static DAYS: &[Day] = &[
+    Day {
+        name: "01",
+        input: day01::INPUT,
+        parse: parse!(bytes: day01::parse),
+        parts: parts!(bytes: day01::part1, day01::part2),
+    },
+    Day {
+        name: "02",
+        input: day02::INPUT,
+        parse: parse!(bytes: day02::parse),
+        parts: parts!(bytes: day02::part1, day02::part2),
+    },
+    Day {
+        name: "03",
+        input: day03::INPUT.as_bytes(),
+        parse: parse!(str: day03::parse),
+        parts: parts!(str: day03::part1, day03::part2),
+    },
+    Day {
+        name: "04",
+        input: day04::INPUT,
+        parse: parse!(bytes: day04::parse),
+        parts: parts!(bytes: day04::part1, day04::part2),
+    },
+    Day {
+        name: "05",
+        input: day05::INPUT,
+        parse: parse!(bytes: day05::parse),
+        parts: parts!(bytes: day05::part1, day05::part2),
+    },
+    Day {
+        name: "06",
+        input: day06::INPUT,
+        parse: parse!(bytes: day06::parse),
+        parts: &[
+            |input, _| Ok(day06::part1(input).to_string()),
+            |input, cancel| day06::part2_with_cancel(input, cancel).map(|r| r.to_string()),
+        ],
+    },
+    Day {
+        name: "07",
+        input: day07::INPUT.as_bytes(),
+        parse: parse!(str: day07::parse),
+        parts: parts!(str: day07::part1, day07::part2),
+    },
+    Day {
+        name: "08",
+        input: day08::INPUT,
+        parse: parse!(bytes: day08::parse),
+        parts: parts!(bytes: day08::part1, day08::part2),
+    },
+    Day {
+        name: "09",
+        input: day09::INPUT,
+        parse: parse!(bytes: day09::parse),
+        parts: parts!(bytes: day09::part1, day09::part2),
+    },
+    Day {
+        name: "10",
+        input: day10::INPUT,
+        parse: parse!(bytes: day10::parse),
+        parts: parts!(bytes: day10::part1, day10::part2),
+    },
+    Day {
+        name: "11",
+        input: day11::INPUT.as_bytes(),
+        parse: parse!(str: day11::parse),
+        parts: parts!(str: day11::part1, day11::part2),
+    },
+    Day {
+        name: "12",
+        input: day12::INPUT,
+        parse: parse!(bytes: day12::parse),
+        parts: parts!(bytes: day12::part1, day12::part2),
+    },
+    Day {
+        name: "13",
+        input: day13::INPUT,
+        parse: parse!(bytes: day13::parse),
+        parts: parts!(bytes: day13::part1, day13::part2),
+    },
+    Day {
+        name: "14",
+        input: day14::INPUT.as_bytes(),
+        parse: parse!(str: day14::parse),
+        parts: &[
+            |input, _| Ok(day14::part1(str_from_bytes(input)).to_string()),
+            |input, cancel| {
+                day14::part2_with_cancel(str_from_bytes(input), cancel)
+                    .map(|r| r.map_or("no frame".to_string(), |steps| steps.to_string()))
+            },
+        ],
+    },
+    Day {
+        name: "15",
+        input: day15::INPUT,
+        parse: parse!(bytes: day15::parse),
+        parts: parts!(bytes: day15::part1, day15::part2),
+    },
+    Day {
+        name: "16",
+        input: day16::INPUT,
+        parse: parse!(bytes: day16::parse),
+        parts: parts!(bytes: day16::part1, day16::part2),
+    },
+    Day {
+        name: "17",
+        input: day17::INPUT,
+        parse: parse!(bytes: day17::parse),
+        parts: &[
+            |input, cancel| day17::part1_with_cancel(input, cancel),
+            |input, cancel| {
+                day17::part2_with_cancel(input, cancel)
+                    .map(|r| r.map_or("no solution".to_string(), |a| a.to_string()))
+            },
+        ],
+    },
+    Day {
+        name: "18",
+        input: day18::INPUT,
+        parse: parse!(bytes: day18::parse),
+        parts: parts!(bytes: day18::part1, day18::part2),
+    },
+    Day {
+        name: "19",
+        input: day19::INPUT,
+        parse: parse!(bytes: day19::parse),
+        parts: parts!(bytes: day19::part1, day19::part2),
+    },
+    Day {
+        name: "20",
+        input: day20::INPUT,
+        parse: parse!(bytes: day20::parse),
+        parts: parts!(bytes: day20::part1, day20::part2),
+    },
+    Day {
+        name: "21",
+        input: day21::INPUT,
+        parse: parse!(bytes: day21::parse),
+        parts: parts!(bytes: day21::part1, day21::part2),
+    },
+    Day {
+        name: "22",
+        input: day22::INPUT,
+        parse: parse!(bytes: day22::parse),
+        parts: parts!(bytes: day22::part1, day22::part2),
+    },
+    Day {
+        name: "23",
+        input: day23::INPUT,
+        parse: parse!(bytes: day23::parse),
+        parts: parts!(bytes: day23::part1, day23::part2),
+    },
+    Day {
+        name: "24",
+        input: day24::INPUT,
+        parse: parse!(bytes: day24::parse),
+        parts: parts!(bytes: day24::part1, day24::part2),
+    },
+    Day {
+        name: "25",
+        input: day25::INPUT,
+        parse: parse!(bytes: day25::parse),
+        parts: parts!(bytes: day25::part1),
+    },
+];
+
+fn arg<T: FromStr>(args: &[&str], ix: usize) -> T {
+    args.get(ix)
+        .and_then(|s| s.parse().ok())
+        .unwrap_or_else(|| panic!("missing or invalid argument {}", ix + 1))
+}
+
+pub fn commands(day: &str) -> &'static [Command] {
+    match day {
+        "01" => &[
+            ("distance", "<column> <column>", |input, args| {
+                let columns = day01::parse_columns(input).unwrap();
+                day01::distance(
+                    &columns[arg::<usize>(args, 0)],
+                    &columns[arg::<usize>(args, 1)],
+                )
+                .to_string()
+            }),
+            ("similarity", "<column> <column>", |input, args| {
+                let columns = day01::parse_columns(input).unwrap();
+                day01::similarity(
+                    &columns[arg::<usize>(args, 0)],
+                    &columns[arg::<usize>(args, 1)],
+                )
+                .to_string()
+            }),
+            ("report", "", |input, _| {
+                let (left, right) = day01::parse(input).unwrap();
+                day01::report(&left, &right).to_string()
+            }),
+        ],
+        "02" => &[
+            ("explain", "", |input, _| {
+                day02::explain(input, &day02::PART2)
+            }),
+            ("safe", "<min step> <max step> <removals>", |input, args| {
+                let rules = day02::Rules {
+                    min_step: arg(args, 0),
+                    max_step: arg(args, 1),
+                    removals: arg(args, 2),
+                };
+                day02::count_safe(input, &rules).to_string()
+            }),
+        ],
+        "03" => &[
+            ("annotate", "", |input, _| {
+                day03::annotate(str_from_bytes(input), day03::Style::Ansi)
+            }),
+            ("html", "", |input, _| {
+                day03::annotate(str_from_bytes(input), day03::Style::Html)
+            }),
+        ],
+        "04" => &[
+            ("find", "<word>", |input, args| {
+                let word = args.first().expect("missing argument 1");
+                let grid = Grid::from_bytes(input).unwrap();
+                let matches = day04::find_word(&grid, word.as_bytes(), &day04::Direction::ALL);
+
+                let mut lines = matches
+                    .iter()
+                    .map(|m| format!("{},{} {:?}", m.row, m.col, m.direction))
+                    .collect::<Vec<String>>();
+                lines.push(format!("{} matches", matches.len()));
+                lines.join("\n")
+            }),
+            ("count", "<word> <word> ...", |input, args| {
+                let words = args.iter().map(|w| w.as_bytes()).collect::<Vec<&[u8]>>();
+                let counts = day04::count_words(&Grid::from_bytes(input).unwrap(), &words);
+
+                args.iter()
+                    .zip(counts)
+                    .map(|(word, count)| format!("{}: {}", word, count))
+                    .collect::<Vec<String>>()
+                    .join("\n")
+            }),
+            ("pattern", "<row> <row> ...", |input, args| {
+                let pattern = day04::Pattern::from_bytes(args.join("\n").as_bytes());
+                let grid = Grid::from_bytes(input).unwrap();
+                let matches = day04::find_pattern(&grid, &pattern, &day04::Orientation::all());
+
+                let mut lines = matches
+                    .iter()
+                    .map(|m| {
+                        format!(
+                            "{},{} turns {}{}",
+                            m.row,
+                            m.col,
+                            m.orientation.turns,
+                            if m.orientation.mirrored {
+                                " mirrored"
+                            } else {
+                                ""
+                            }
+                        )
+                    })
+                    .collect::<Vec<String>>();
+                lines.push(format!("{} matches", matches.len()));
+                lines.join("\n")
+            }),
+        ],
+        "05" => &[("audit", "", |input, _| day05::audit_report(input))],
+        "06" => &[
+            ("loops", "", |input, _| {
+                day06::loops(input)
+                    .iter()
+                    .map(|l| format!("{},{}: {}", l.obstruction.0, l.obstruction.1, l.length))
+                    .collect::<Vec<String>>()
+                    .join("\n")
+            }),
+            ("patrol", "<right|left|reverse> <4|8>", |input, args| {
+                let turn = match args.first().copied() {
+                    Some("right") => day06::Turn::Right,
+                    Some("left") => day06::Turn::Left,
+                    Some("reverse") => day06::Turn::Reverse,
+                    _ => panic!("missing or invalid argument 1"),
+                };
+                let rules = day06::Rules {
+                    turn,
+                    diagonal: arg::<usize>(args, 1) == 8,
+                    ..day06::Rules::default()
+                };
+
+                day06::simulate(input, &rules)
+                    .iter()
+                    .map(|r| {
+                        format!(
+                            "{},{} {:?}: {} cells{}",
+                            r.start.0,
+                            r.start.1,
+                            r.direction,
+                            r.visited,
+                            if r.looped { ", loops" } else { "" }
+                        )
+                    })
+                    .collect::<Vec<String>>()
+                    .join("\n")
+            }),
+            ("render", "<row> <col>", |input, args| {
+                let obstruction = (arg(args, 0), arg(args, 1));
+                match day06::loops(input)
+                    .iter()
+                    .find(|l| l.obstruction == obstruction)
+                {
+                    Some(selected) => day06::render(input, selected),
+                    None => "no loop for this obstruction".to_string(),
+                }
+            }),
+        ],
+        "07" => &[("solve", "<op,op,...> [all]", |input, args| {
+            let names = args.first().expect("missing argument 1");
+            let operators = names
+                .split(',')
+                .map(|name| {
+                    day07::operator(name).unwrap_or_else(|| panic!("invalid operator: {}", name))
+                })
+                .collect::<Vec<Box<dyn day07::Operator>>>();
+            let operators = operators
+                .iter()
+                .map(|op| op.as_ref())
+                .collect::<Vec<&dyn day07::Operator>>();
+
+            day07::explain(
+                str_from_bytes(input),
+                &operators,
+                args.get(1) == Some(&"all"),
+            )
+        })],
+        "08" => &[(
+            "antinodes",
+            "<1|2|multiples k,k,...|ratio num den>",
+            |input, args| match args.first().copied() {
+                Some("1") => day08::explain(input, &day08::PART1),
+                Some("2") => day08::explain(input, &day08::PART2),
+                Some("multiples") => {
+                    let multiples = args
+                        .get(1)
+                        .expect("missing argument 2")
+                        .split(',')
+                        .map(|k| k.parse().expect("invalid multiple"))
+                        .collect::<Vec<isize>>();
+                    day08::explain(input, &day08::Multiples(&multiples))
+                }
+                Some("ratio") => {
+                    let rule = day08::Ratio::new(arg(args, 1), arg(args, 2))
+                        .expect("ratio must be positive");
+                    day08::explain(input, &rule)
+                }
+                _ => panic!("missing or invalid argument 1"),
+            },
+        )],
+        "13" => &[("solve", "<offset>", |input, args| {
+            day13::solve(input, arg(args, 0)).to_string()
+        })],
+        "14" => &[
+            ("part1", "<width> <height>", |input, args| {
+                day14::part1_with_bounds(str_from_bytes(input), arg(args, 0), arg(args, 1))
+                    .to_string()
+            }),
+            ("part2", "<width> <height>", |input, args| {
+                let cancel = Cancel::default();
+                day14::part2_with_bounds(str_from_bytes(input), arg(args, 0), arg(args, 1), &cancel)
+                    .unwrap()
+                    .map_or("no frame".to_string(), |steps| steps.to_string())
+            }),
+        ],
+        "16" => &[("junctions", "", |input, _| day16::junctions(input))],
+        "18" => &[
+            (
+                "part1",
+                "<grid size> <steps>",
+                |input, args| match day18::part1_with_parameters(input, arg(args, 0), arg(args, 1))
+                {
+                    Some(steps) => steps.to_string(),
+                    None => "no path".to_string(),
+                },
+            ),
+            (
+                "part2",
+                "<grid size>",
+                |input, args| match day18::part2_with_parameters(input, arg(args, 0)) {
+                    Some((col, row)) => format!("{},{}", col, row),
+                    None => "no blocking byte".to_string(),
+                },
+            ),
+        ],
+        "20" => &[("cheats", "<cheat length>", |input, args| {
+            let mut saved = day20::solve(input, arg(args, 0))
+                .into_iter()
+                .collect::<Vec<(usize, usize)>>();
+            saved.sort();
+            saved
+                .into_iter()
+                .map(|(saved, count)| format!("{}: {}", saved, count))
+                .collect::<Vec<String>>()
+                .join("\n")
+        })],
+        "23" => &[("adjacency", "", |input, _| day23::adjacency(input))],
+        "24" => &[("gates", "", |input, _| day24::gates(input))],
+        _ => &[],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(day: &str, command: &str, input: &[u8], args: &[&str]) -> String {
+        let (_, _, run) = commands(day)
+            .iter()
+            .find(|(name, _, _)| *name == command)
+            .unwrap();
+        run(input.trim_ascii_end(), args)
+    }
+
+    #[test]
+    fn test_commands() {
+        let input = include_bytes!("../test_inputs/day08.txt");
+        let invalid =
+            std::panic::catch_unwind(|| run("08", "antinodes", input, &["ratio", "0", "0"]));
+        assert!(invalid.is_err());
+        assert!(run("08", "antinodes", input, &["2"]).ends_with("\n34 antinodes"));
+        assert_eq!(
+            run("08", "antinodes", input, &["multiples", "1"]),
+            run("08", "antinodes", input, &["1"])
+        );
+
+        let input = include_bytes!("../test_inputs/day07.txt");
+        assert!(
+            run("07", "solve", input, &["add,mul"])
+                .starts_with("190: 10 * 19 (1 solution)\n3267: 81 * 40 + 27 (2 solutions)")
+        );
+
+        let input = include_bytes!("../test_inputs/day06.txt");
+        assert_eq!(
+            run("06", "patrol", input, &["left", "4"]),
+            "6,4 Up: 10 cells"
+        );
+
+        let input = include_bytes!("../test_inputs/day04.txt");
+        assert!(run("04", "find", input, &["XMAS"]).ends_with("18 matches"));
+        assert!(run("04", "pattern", input, &["M.S", ".A.", "M.S"]).ends_with("9 matches"));
+
+        let input = include_bytes!("../test_inputs/day01.txt");
+        assert_eq!(run("01", "distance", input, &["1", "0"]), "11");
+        assert_eq!(run("01", "similarity", input, &["0", "1"]), "31");
+
+        let input = include_bytes!("../test_inputs/day14.txt");
+        assert_eq!(run("14", "part1", input, &["11", "7"]), "12");
+
+        let input = include_bytes!("../test_inputs/day18.txt");
+        assert_eq!(run("18", "part1", input, &["7", "12"]), "22");
+
+        let input = include_bytes!("../test_inputs/day23.txt");
+        assert!(
+            run("23", "adjacency", input, &[])
+                .lines()
+                .any(|line| line == "ka: co,de,ta,tb")
+        );
+    }
+}